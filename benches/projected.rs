@@ -0,0 +1,49 @@
+//! Compares repeated projection with a [`Projected`] cache.
+//!
+//! Run with `cargo bench --features bench`.
+
+#![allow(clippy::incompatible_msrv)]
+
+use borrow_or_share::{BorrowOrShare, Projected};
+use std::{hint::black_box, time::Instant};
+
+const ITERS: u32 = 10_000_000;
+
+struct Text<T>(T);
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+    fn as_str(&'i self) -> &'o str {
+        self.0.borrow_or_share()
+    }
+}
+
+fn repeated(text: &Text<String>) -> usize {
+    let mut total = 0;
+    for _ in 0..ITERS {
+        total += black_box(text).as_str().len();
+    }
+    total
+}
+
+fn cached(text: &Text<String>) -> usize {
+    let projected = Projected::<str>::new(&text.0);
+    let mut total = 0;
+    for _ in 0..ITERS {
+        total += black_box(projected).len();
+    }
+    total
+}
+
+fn bench(name: &str, f: fn(&Text<String>) -> usize) {
+    let text = Text(String::from("hello, world"));
+    let start = Instant::now();
+    let total = black_box(f(&text));
+    let elapsed = start.elapsed();
+    assert_eq!(total, 12 * ITERS as usize);
+    println!("{name:>8}: {:.2} ns/iter", elapsed.as_nanos() as f64 / ITERS as f64);
+}
+
+fn main() {
+    bench("repeated", repeated);
+    bench("cached", cached);
+}
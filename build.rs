@@ -0,0 +1,54 @@
+use std::{env, process::Command, str};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(bos_once_cell)");
+    println!("cargo:rustc-check-cfg=cfg(bos_diagnostic)");
+    println!("cargo:rustc-check-cfg=cfg(bos_lazy_lock)");
+    println!("cargo:rustc-check-cfg=cfg(bos_os_str_bytes)");
+    println!("cargo:rustc-check-cfg=cfg(bos_nightly_rustc)");
+
+    let (minor, nightly) = match rustc_version() {
+        Some(version) => version,
+        None => return,
+    };
+
+    // Diagnostics on nightly compilers may differ from the expected output
+    // of the UI tests, which is checked on stable.
+    if nightly {
+        println!("cargo:rustc-cfg=bos_nightly_rustc");
+    }
+
+    // `OnceCell` and `OnceLock` were stabilized in Rust 1.70.
+    if minor >= 70 {
+        println!("cargo:rustc-cfg=bos_once_cell");
+    }
+
+    // The `#[diagnostic]` attribute namespace was stabilized in Rust 1.78.
+    if minor >= 78 {
+        println!("cargo:rustc-cfg=bos_diagnostic");
+    }
+
+    // `OsStr::as_encoded_bytes` was stabilized in Rust 1.74.
+    if minor >= 74 {
+        println!("cargo:rustc-cfg=bos_os_str_bytes");
+    }
+
+    // `LazyLock` was stabilized in Rust 1.80.
+    if minor >= 80 {
+        println!("cargo:rustc-cfg=bos_lazy_lock");
+    }
+}
+
+fn rustc_version() -> Option<(u32, bool)> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = str::from_utf8(&output.stdout).ok()?;
+    let mut pieces = version.split('.');
+    if pieces.next() != Some("rustc 1") {
+        return None;
+    }
+    let minor = pieces.next()?.parse().ok()?;
+    let nightly = version.contains("-nightly") || version.contains("-dev");
+    Some((minor, nightly))
+}
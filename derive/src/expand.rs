@@ -0,0 +1,186 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Attribute, Data, DeriveInput, Error, Ident, Member, Path, Result, Type};
+
+/// The options given in `#[bos(...)]` attributes on the struct.
+struct Options {
+    targets: Vec<Type>,
+    krate: Path,
+}
+
+/// Expands `#[derive(Bos)]` on the given input.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let options = parse_options(&input.attrs)?;
+    let (member, ty) = holder_field(input)?;
+
+    if options.targets.is_empty() {
+        return Ok(forward(input, &options.krate, &member, &ty, None));
+    }
+    Ok(options
+        .targets
+        .iter()
+        .map(|target| forward(input, &options.krate, &member, &ty, Some(target)))
+        .collect())
+}
+
+fn is_bos(attr: &Attribute) -> bool {
+    attr.path().is_ident("bos")
+}
+
+fn parse_options(attrs: &[Attribute]) -> Result<Options> {
+    let mut targets = Vec::new();
+    let mut krate = None;
+    for attr in attrs.iter().filter(|attr| is_bos(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("target") {
+                targets.push(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                if krate.is_some() {
+                    return Err(meta.error("duplicate `crate` attribute"));
+                }
+                krate = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                let msg = "unsupported `bos` attribute, \
+                           expected `target = Type` or `crate = path`";
+                Err(meta.error(msg))
+            }
+        })?;
+    }
+    Ok(Options {
+        targets,
+        krate: krate.unwrap_or_else(|| parse_quote!(::borrow_or_share)),
+    })
+}
+
+fn holder_field(input: &DeriveInput) -> Result<(Member, Type)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            let msg = "`#[derive(Bos)]` only supports structs";
+            return Err(Error::new_spanned(data.enum_token, msg));
+        }
+        Data::Union(data) => {
+            let msg = "`#[derive(Bos)]` only supports structs";
+            return Err(Error::new_spanned(data.union_token, msg));
+        }
+    };
+
+    let mut marked = None;
+    for (i, field) in fields.iter().enumerate() {
+        for attr in field.attrs.iter().filter(|attr| is_bos(attr)) {
+            attr.meta.require_path_only()?;
+            if marked.is_some() {
+                let msg = "only one field may be marked `#[bos]`";
+                return Err(Error::new_spanned(attr, msg));
+            }
+            marked = Some((i, field));
+        }
+    }
+
+    let (i, field) = match (marked, fields) {
+        (Some(marked), _) => marked,
+        (None, _) if fields.len() == 1 => (0, fields.iter().next().unwrap()),
+        (None, _) if fields.is_empty() => {
+            let msg = "`#[derive(Bos)]` requires a field holding the data";
+            return Err(Error::new_spanned(&input.ident, msg));
+        }
+        (None, _) => {
+            let msg = "`#[derive(Bos)]` on a struct with multiple fields \
+                       requires the field holding the data to be marked `#[bos]`";
+            return Err(Error::new_spanned(&input.ident, msg));
+        }
+    };
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(i.into()),
+    };
+    Ok((member, field.ty.clone()))
+}
+
+fn forward(
+    input: &DeriveInput,
+    krate: &Path,
+    member: &Member,
+    fty: &Type,
+    target: Option<&Type>,
+) -> TokenStream {
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut generics = input.generics.clone();
+    let target: Type = match target {
+        Some(target) => target.clone(),
+        None => {
+            let param = Ident::new("__BosTarget", Span::call_site());
+            generics
+                .params
+                .push(parse_quote!(#param: ?::core::marker::Sized));
+            parse_quote!(#param)
+        }
+    };
+    let base_where = generics.make_where_clause().clone();
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let mut shares_generics = generics.clone();
+    shares_generics.params.insert(0, parse_quote!('__a));
+    let (shares_impl_generics, _, _) = shares_generics.split_for_impl();
+
+    let where_clause = |bound: TokenStream| {
+        let mut where_clause = base_where.clone();
+        where_clause
+            .predicates
+            .push(parse_quote!(#fty: #krate::#bound));
+        where_clause
+    };
+    let bos_where = where_clause(quote!(Bos<#target>));
+    let try_bos_where = where_clause(quote!(TryBos<#target>));
+    let borrows_where = where_clause(quote!(BorrowsFrom<#target>));
+    let shares_with_where = where_clause(quote!(SharesWith<#target>));
+    let shares_where = where_clause(quote!(Shares<'__a, #target>));
+    let stable_where = where_clause(quote!(StableBos<#target>));
+
+    quote! {
+        impl #impl_generics #krate::Bos<#target> for #name #ty_generics #bos_where {
+            type Ref<'this> = <#fty as #krate::Bos<#target>>::Ref<'this>
+            where
+                Self: 'this;
+
+            #[inline]
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                <#fty as #krate::Bos<#target>>::borrow_or_share(&this.#member)
+            }
+        }
+
+        impl #impl_generics #krate::TryBos<#target> for #name #ty_generics #try_bos_where {
+            type Ref<'this> = <#fty as #krate::TryBos<#target>>::Ref<'this>
+            where
+                Self: 'this;
+
+            #[inline]
+            fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+                <#fty as #krate::TryBos<#target>>::try_borrow_or_share(&this.#member)
+            }
+        }
+
+        impl #impl_generics #krate::BorrowsFrom<#target> for #name #ty_generics
+            #borrows_where {}
+
+        impl #impl_generics #krate::SharesWith<#target> for #name #ty_generics
+            #shares_with_where {}
+
+        impl #shares_impl_generics #krate::Shares<'__a, #target> for #name #ty_generics
+            #shares_where
+        {
+            #[inline]
+            fn share(&self) -> &'__a #target {
+                <#fty as #krate::Shares<'__a, #target>>::share(&self.#member)
+            }
+        }
+
+        impl #impl_generics #krate::StableBos<#target> for #name #ty_generics
+            #stable_where {}
+    }
+}
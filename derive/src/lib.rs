@@ -0,0 +1,70 @@
+//! Derive macro for the [`borrow-or-share`] crate.
+//!
+//! This crate is not meant to be used directly. Enable the `derive`
+//! feature of [`borrow-or-share`] instead, which re-exports the macro.
+//!
+//! [`borrow-or-share`]: https://docs.rs/borrow-or-share
+
+#![forbid(unsafe_code)]
+
+mod expand;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Error};
+
+/// Derives `Bos` on a struct by forwarding to the field holding the data.
+///
+/// The field is the only one of the struct, or the one marked `#[bos]` if
+/// there are several. The implementation has the `Ref` type of the field,
+/// so that sharing is preserved. `TryBos`, `Shares`, and the marker traits
+/// `BorrowsFrom`, `SharesWith`, and `StableBos` are derived as well,
+/// on the condition that the field type implements them.
+///
+/// By default, the implementations are generic over the target, i.e.,
+/// the struct implements `Bos<U>` wherever the field type does. Add
+/// `#[bos(target = Type)]` to the struct to only implement it for
+/// the given target instead. The attribute may be repeated.
+///
+/// The generated code refers to the traits as `::borrow_or_share::Bos`
+/// and so on. If the dependency is renamed or re-exported from another
+/// crate, give the path to it with `#[bos(crate = path)]`, e.g.,
+/// `#[bos(crate = ::my_crate::bos)]`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Bos};
+///
+/// #[derive(Bos)]
+/// struct Name<T>(T);
+///
+/// #[derive(Bos)]
+/// #[bos(target = str)]
+/// struct Label<T> {
+///     #[bos]
+///     text: T,
+///     id: u32,
+/// }
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// // The data is shared for `'a`, as with `&'a str`.
+/// fn share<'a>(name: &Name<&'a str>) -> &'a str {
+///     as_str(name)
+/// }
+///
+/// assert_eq!(as_str(&Name(String::from("alice"))), "alice");
+/// assert_eq!(share(&Name("bob")), "bob");
+///
+/// let label = Label { text: "main", id: 1 };
+/// assert_eq!(as_str(&label), "main");
+/// ```
+#[proc_macro_derive(Bos, attributes(bos))]
+pub fn derive_bos(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,41 @@
+//! Snapshot tests of the code generated by `#[derive(Bos)]`.
+//!
+//! Each `tests/expand/NAME.rs` holds a struct to derive on, and
+//! `tests/expand/NAME.expanded.rs` the expected expansion. Run with
+//! `BOS_EXPAND=overwrite` to write the snapshots anew after a change.
+
+#[path = "../src/expand.rs"]
+mod expand;
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn expand() {
+    let overwrite = env::var("BOS_EXPAND").as_deref() == Ok("overwrite");
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+
+    let mut inputs: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| !path.to_str().unwrap().ends_with(".expanded.rs"))
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty());
+
+    let mut mismatched = Vec::new();
+    for input in &inputs {
+        let src = fs::read_to_string(input).unwrap();
+        let derive_input = syn::parse_str(&src).unwrap();
+        let tokens = expand::expand(&derive_input).unwrap();
+        let actual = prettyplease::unparse(&syn::parse2(tokens).unwrap());
+
+        let snapshot = input.with_extension("expanded.rs");
+        if overwrite {
+            fs::write(&snapshot, &actual).unwrap();
+        } else if fs::read_to_string(&snapshot).ok().as_deref() != Some(&actual) {
+            eprintln!("{} expands to:\n{actual}", input.display());
+            mismatched.push(snapshot);
+        }
+    }
+    assert!(mismatched.is_empty(), "snapshots mismatched: {mismatched:?}");
+}
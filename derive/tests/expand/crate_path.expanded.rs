@@ -0,0 +1,60 @@
+impl<T, __BosTarget: ?::core::marker::Sized> ::my_crate::bos::Bos<__BosTarget>
+for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::Bos<__BosTarget>,
+{
+    type Ref<'this> = <T as ::my_crate::bos::Bos<__BosTarget>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        <T as ::my_crate::bos::Bos<__BosTarget>>::borrow_or_share(&this.0)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::my_crate::bos::TryBos<__BosTarget>
+for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::TryBos<__BosTarget>,
+{
+    type Ref<'this> = <T as ::my_crate::bos::TryBos<__BosTarget>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+        <T as ::my_crate::bos::TryBos<__BosTarget>>::try_borrow_or_share(&this.0)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::my_crate::bos::BorrowsFrom<__BosTarget>
+for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::BorrowsFrom<__BosTarget>,
+{}
+impl<T, __BosTarget: ?::core::marker::Sized> ::my_crate::bos::SharesWith<__BosTarget>
+for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::SharesWith<__BosTarget>,
+{}
+impl<
+    '__a,
+    T,
+    __BosTarget: ?::core::marker::Sized,
+> ::my_crate::bos::Shares<'__a, __BosTarget> for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::Shares<'__a, __BosTarget>,
+{
+    #[inline]
+    fn share(&self) -> &'__a __BosTarget {
+        <T as ::my_crate::bos::Shares<'__a, __BosTarget>>::share(&self.0)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::my_crate::bos::StableBos<__BosTarget>
+for Renamed<T>
+where
+    T: Clone,
+    T: ::my_crate::bos::StableBos<__BosTarget>,
+{}
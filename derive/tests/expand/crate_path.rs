@@ -0,0 +1,4 @@
+#[bos(crate = ::my_crate::bos)]
+struct Renamed<T>(T)
+where
+    T: Clone;
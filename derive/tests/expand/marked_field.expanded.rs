@@ -0,0 +1,54 @@
+impl<T, __BosTarget: ?::core::marker::Sized> ::borrow_or_share::Bos<__BosTarget>
+for Label<T>
+where
+    T: ::borrow_or_share::Bos<__BosTarget>,
+{
+    type Ref<'this> = <T as ::borrow_or_share::Bos<__BosTarget>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        <T as ::borrow_or_share::Bos<__BosTarget>>::borrow_or_share(&this.text)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::borrow_or_share::TryBos<__BosTarget>
+for Label<T>
+where
+    T: ::borrow_or_share::TryBos<__BosTarget>,
+{
+    type Ref<'this> = <T as ::borrow_or_share::TryBos<__BosTarget>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+        <T as ::borrow_or_share::TryBos<__BosTarget>>::try_borrow_or_share(&this.text)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::borrow_or_share::BorrowsFrom<__BosTarget>
+for Label<T>
+where
+    T: ::borrow_or_share::BorrowsFrom<__BosTarget>,
+{}
+impl<T, __BosTarget: ?::core::marker::Sized> ::borrow_or_share::SharesWith<__BosTarget>
+for Label<T>
+where
+    T: ::borrow_or_share::SharesWith<__BosTarget>,
+{}
+impl<
+    '__a,
+    T,
+    __BosTarget: ?::core::marker::Sized,
+> ::borrow_or_share::Shares<'__a, __BosTarget> for Label<T>
+where
+    T: ::borrow_or_share::Shares<'__a, __BosTarget>,
+{
+    #[inline]
+    fn share(&self) -> &'__a __BosTarget {
+        <T as ::borrow_or_share::Shares<'__a, __BosTarget>>::share(&self.text)
+    }
+}
+impl<T, __BosTarget: ?::core::marker::Sized> ::borrow_or_share::StableBos<__BosTarget>
+for Label<T>
+where
+    T: ::borrow_or_share::StableBos<__BosTarget>,
+{}
@@ -0,0 +1,5 @@
+struct Label<T> {
+    #[bos]
+    text: T,
+    id: u32,
+}
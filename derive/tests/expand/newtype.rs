@@ -0,0 +1 @@
+struct Name<T>(T);
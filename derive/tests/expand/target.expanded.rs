@@ -0,0 +1,90 @@
+impl<'a, T: ?Sized> ::borrow_or_share::Bos<str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::Bos<str>,
+{
+    type Ref<'this> = <&'a T as ::borrow_or_share::Bos<str>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        <&'a T as ::borrow_or_share::Bos<str>>::borrow_or_share(&this.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::TryBos<str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::TryBos<str>,
+{
+    type Ref<'this> = <&'a T as ::borrow_or_share::TryBos<str>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+        <&'a T as ::borrow_or_share::TryBos<str>>::try_borrow_or_share(&this.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::BorrowsFrom<str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::BorrowsFrom<str>,
+{}
+impl<'a, T: ?Sized> ::borrow_or_share::SharesWith<str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::SharesWith<str>,
+{}
+impl<'__a, 'a, T: ?Sized> ::borrow_or_share::Shares<'__a, str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::Shares<'__a, str>,
+{
+    #[inline]
+    fn share(&self) -> &'__a str {
+        <&'a T as ::borrow_or_share::Shares<'__a, str>>::share(&self.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::StableBos<str> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::StableBos<str>,
+{}
+impl<'a, T: ?Sized> ::borrow_or_share::Bos<[u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::Bos<[u8]>,
+{
+    type Ref<'this> = <&'a T as ::borrow_or_share::Bos<[u8]>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        <&'a T as ::borrow_or_share::Bos<[u8]>>::borrow_or_share(&this.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::TryBos<[u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::TryBos<[u8]>,
+{
+    type Ref<'this> = <&'a T as ::borrow_or_share::TryBos<[u8]>>::Ref<'this>
+    where
+        Self: 'this;
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+        <&'a T as ::borrow_or_share::TryBos<[u8]>>::try_borrow_or_share(&this.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::BorrowsFrom<[u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::BorrowsFrom<[u8]>,
+{}
+impl<'a, T: ?Sized> ::borrow_or_share::SharesWith<[u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::SharesWith<[u8]>,
+{}
+impl<'__a, 'a, T: ?Sized> ::borrow_or_share::Shares<'__a, [u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::Shares<'__a, [u8]>,
+{
+    #[inline]
+    fn share(&self) -> &'__a [u8] {
+        <&'a T as ::borrow_or_share::Shares<'__a, [u8]>>::share(&self.0)
+    }
+}
+impl<'a, T: ?Sized> ::borrow_or_share::StableBos<[u8]> for Text<'a, T>
+where
+    &'a T: ::borrow_or_share::StableBos<[u8]>,
+{}
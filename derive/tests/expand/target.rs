@@ -0,0 +1,3 @@
+#[bos(target = str)]
+#[bos(target = [u8])]
+struct Text<'a, T: ?Sized>(&'a T);
@@ -0,0 +1,107 @@
+use crate::{BorrowsFrom, Bos, BosMut, Shares, SharesWith, StableBos, TryBos};
+
+/// A holder carrying metadata along with it.
+///
+/// This is useful for attaching e.g. a source span or provenance to a
+/// holder without disturbing code that is generic over [`Bos`]. An
+/// `Annotated<B, M>` implements [`Bos<T>`] by forwarding to the holder,
+/// preserving its sharing behavior, and ignores the metadata.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{Annotated, BorrowOrShare};
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let ident = Annotated::new(String::from("main"), 3..7);
+/// assert_eq!(as_str(&ident), "main");
+///
+/// let ident = ident.map_meta(|span| span.len());
+/// assert_eq!(ident.meta, 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Annotated<B, M> {
+    /// The holder.
+    pub holder: B,
+    /// The metadata.
+    pub meta: M,
+}
+
+impl<B, M> Annotated<B, M> {
+    /// Pairs the holder with the metadata.
+    #[inline]
+    pub const fn new(holder: B, meta: M) -> Self {
+        Annotated { holder, meta }
+    }
+
+    /// Maps the metadata with a function, keeping the holder.
+    #[inline]
+    pub fn map_meta<N, F: FnOnce(M) -> N>(self, f: F) -> Annotated<B, N> {
+        Annotated::new(self.holder, f(self.meta))
+    }
+
+    /// Returns references to the holder and the metadata.
+    #[inline]
+    pub fn as_parts(&self) -> (&B, &M) {
+        (&self.holder, &self.meta)
+    }
+
+    /// Splits into the holder and the metadata.
+    #[inline]
+    pub fn into_parts(self) -> (B, M) {
+        (self.holder, self.meta)
+    }
+}
+
+impl<T: ?Sized, B: Bos<T>, M> Bos<T> for Annotated<B, M> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder)
+    }
+}
+
+impl<T: ?Sized, B: BorrowsFrom<T>, M> BorrowsFrom<T> for Annotated<B, M> {}
+impl<T: ?Sized, B: SharesWith<T>, M> SharesWith<T> for Annotated<B, M> {}
+impl<T: ?Sized, B: StableBos<T>, M> StableBos<T> for Annotated<B, M> {}
+
+impl<'a, T: ?Sized, B: Shares<'a, T>, M> Shares<'a, T> for Annotated<B, M> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self.holder.share()
+    }
+}
+
+impl<T: ?Sized, B: TryBos<T>, M> TryBos<T> for Annotated<B, M> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder)
+    }
+}
+
+impl<T: ?Sized, B: BosMut<T>, M> BosMut<T> for Annotated<B, M> {
+    type RefMut<'this> = B::RefMut<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        B::borrow_or_share_mut(&mut this.holder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, M> crate::BosToOwned<T> for Annotated<B, M>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+    B: crate::BosToOwned<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.holder.into_owned_target()
+    }
+}
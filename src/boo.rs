@@ -0,0 +1,195 @@
+use crate::{BorrowsFrom, Bos, BosToOwned, TryBos};
+use alloc::{
+    borrow::{Borrow, Cow, ToOwned},
+    ffi::CString,
+    string::String,
+    vec::Vec,
+};
+use core::{ffi::CStr, fmt};
+
+#[cfg(feature = "std")]
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+/// Data of type `T` that is either borrowed for `'a` or owned.
+///
+/// Unlike [`Cow<'a, T>`], this type has no clone-on-write API, and is
+/// meant to be used as a holder with APIs bounded on [`Bos<T>`]. Its
+/// [`Bos<T>`] implementation borrows from `*self` in both cases, because
+/// the variant is not known statically. Use [`Boo::shared`] to get at the
+/// longer-lived reference in the borrowed case.
+///
+/// It converts from `&'a T`, from [`Cow<'a, T>`], and from the owned forms
+/// of `str`, `[T]`, `CStr`, `OsStr`, and `Path`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Boo};
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// let borrowed = Text(Boo::from("hello"));
+/// let owned = Text(Boo::from(String::from("hello")));
+/// assert_eq!(borrowed.as_str(), owned.as_str());
+/// assert!(borrowed.0.is_borrowed());
+/// assert_eq!(owned.0.into_owned(), "hello");
+/// ```
+pub enum Boo<'a, T: ?Sized + ToOwned> {
+    /// Data borrowed for `'a`.
+    Borrowed(&'a T),
+    /// Owned data.
+    Owned(T::Owned),
+}
+
+impl<'a, T: ?Sized + ToOwned> Boo<'a, T> {
+    /// Returns `true` if the data is borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Boo::Borrowed(_))
+    }
+
+    /// Returns `true` if the data is owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Returns the data as borrowed for `'a`, or `None` if it is owned.
+    #[inline]
+    pub fn shared(&self) -> Option<&'a T> {
+        match *self {
+            Boo::Borrowed(t) => Some(t),
+            Boo::Owned(_) => None,
+        }
+    }
+
+    /// Returns a reference to the data.
+    #[inline]
+    pub fn as_target(&self) -> &T {
+        match self {
+            Boo::Borrowed(t) => t,
+            Boo::Owned(o) => o.borrow(),
+        }
+    }
+
+    /// Converts the data into owned data, cloning it if it is borrowed.
+    #[inline]
+    pub fn into_owned(self) -> T::Owned {
+        match self {
+            Boo::Borrowed(t) => t.to_owned(),
+            Boo::Owned(o) => o,
+        }
+    }
+}
+
+impl<T: ?Sized + ToOwned> Clone for Boo<'_, T>
+where
+    T::Owned: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Boo::Borrowed(t) => Boo::Borrowed(t),
+            Boo::Owned(o) => Boo::Owned(o.clone()),
+        }
+    }
+}
+
+impl<T: ?Sized + ToOwned + fmt::Debug> fmt::Debug for Boo<'_, T>
+where
+    T::Owned: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Boo::Borrowed(t) => f.debug_tuple("Borrowed").field(t).finish(),
+            Boo::Owned(o) => f.debug_tuple("Owned").field(o).finish(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized + ToOwned> From<&'a T> for Boo<'a, T> {
+    #[inline]
+    fn from(t: &'a T) -> Self {
+        Boo::Borrowed(t)
+    }
+}
+
+impl<'a, T: ?Sized + ToOwned> From<Cow<'a, T>> for Boo<'a, T> {
+    #[inline]
+    fn from(cow: Cow<'a, T>) -> Self {
+        match cow {
+            Cow::Borrowed(t) => Boo::Borrowed(t),
+            Cow::Owned(o) => Boo::Owned(o),
+        }
+    }
+}
+
+impl<'a, T: ?Sized + ToOwned> From<Boo<'a, T>> for Cow<'a, T> {
+    #[inline]
+    fn from(boo: Boo<'a, T>) -> Self {
+        match boo {
+            Boo::Borrowed(t) => Cow::Borrowed(t),
+            Boo::Owned(o) => Cow::Owned(o),
+        }
+    }
+}
+
+macro_rules! impl_from_owned {
+    ($($(#[$attr:meta])* $({$($params:tt)*})? $owned:ty => $target:ty)*) => {
+        $(
+            $(#[$attr])*
+            impl<$($($params)*)?> From<$owned> for Boo<'_, $target> {
+                #[inline]
+                fn from(o: $owned) -> Self {
+                    Boo::Owned(o)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_owned! {
+    String => str
+    {T: Clone} Vec<T> => [T]
+    CString => CStr
+    #[cfg(feature = "std")]
+    OsString => OsStr
+    #[cfg(feature = "std")]
+    PathBuf => Path
+}
+
+impl<T: ?Sized + ToOwned> Bos<T> for Boo<'_, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_target()
+    }
+}
+
+impl<T: ?Sized + ToOwned> BorrowsFrom<T> for Boo<'_, T> {}
+
+impl<T: ?Sized + ToOwned> TryBos<T> for Boo<'_, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.as_target())
+    }
+}
+
+impl<T: ?Sized + ToOwned> BosToOwned<T> for Boo<'_, T> {
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.into_owned()
+    }
+}
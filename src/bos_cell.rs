@@ -0,0 +1,112 @@
+use crate::{BorrowOrShare, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+
+/// A container whose holder may be replaced in place.
+///
+/// `BosCell<B>` keeps a stable view of the data behind a holder `B` while
+/// allowing the holder itself to be swapped out, for example to replace
+/// an owned value with a reference that outlives the cell, or the other way
+/// round when `B` is an enum such as [`Cow`]. Data is accessed through
+/// [`as_ref`](Self::as_ref), which borrows or shares like [`BorrowOrShare`].
+///
+/// [`Cow`]: alloc::borrow::Cow
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::BosCell;
+/// use std::borrow::Cow;
+///
+/// let mut cell = BosCell::new(Cow::Owned(String::from("owned")));
+/// assert_eq!(cell.as_ref::<str>(), "owned");
+///
+/// cell.set(Cow::Borrowed("borrowed"));
+/// assert_eq!(cell.as_ref::<str>(), "borrowed");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BosCell<B>(B);
+
+impl<B> BosCell<B> {
+    /// Creates a cell containing the given holder.
+    #[inline]
+    pub const fn new(b: B) -> Self {
+        BosCell(b)
+    }
+
+    /// Borrows or shares data of type `T` from the holder.
+    ///
+    /// If the holder shares data, the returned reference does not borrow
+    /// from the cell, and remains valid after the holder is replaced.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref<'i, 'o, T: ?Sized>(&'i self) -> &'o T
+    where
+        B: BorrowOrShare<'i, 'o, T>,
+    {
+        self.0.borrow_or_share()
+    }
+
+    /// Replaces the holder, dropping the old one.
+    #[inline]
+    pub fn set(&mut self, b: B) {
+        self.0 = b;
+    }
+
+    /// Replaces the holder, returning the old one.
+    #[inline]
+    pub fn replace(&mut self, b: B) -> B {
+        core::mem::replace(&mut self.0, b)
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.0
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<T: ?Sized, B: Bos<T>> Bos<T> for BosCell<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.0)
+    }
+}
+
+impl<T: ?Sized, B: BorrowsFrom<T>> BorrowsFrom<T> for BosCell<B> {}
+impl<T: ?Sized, B: SharesWith<T>> SharesWith<T> for BosCell<B> {}
+impl<T: ?Sized, B: StableBos<T>> StableBos<T> for BosCell<B> {}
+
+impl<'a, T: ?Sized, B: Shares<'a, T>> Shares<'a, T> for BosCell<B> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self.0.share()
+    }
+}
+
+impl<T: ?Sized, B: TryBos<T>> TryBos<T> for BosCell<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B> crate::BosToOwned<T> for BosCell<B>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+    B: crate::BosToOwned<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.0.into_owned_target()
+    }
+}
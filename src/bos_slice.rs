@@ -0,0 +1,156 @@
+use crate::{bos_of, internal::Map, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+use core::ops::Range;
+
+/// An adapter exposing a subslice of the data of a holder.
+///
+/// A `BosSlice<B>` holds a `B: Bos<[T]>` along with a range, and implements
+/// [`Bos<[T]>`](Bos) by indexing the slice borrowed or shared from the
+/// holder with the range. This is useful for spans, fields, or windows into
+/// a buffer that is held as a whole. Sharing is preserved: if `B` shares
+/// `[T]` for `'a`, then so does the adapter.
+///
+/// The range is checked against the length of the slice on construction.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, BosSlice};
+///
+/// fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+///     b.borrow_or_share()
+/// }
+///
+/// fn share<'a>(slice: &BosSlice<&'a [u8]>) -> &'a [u8] {
+///     bytes(slice)
+/// }
+///
+/// let field = BosSlice::new(vec![1u8, 2, 3, 4, 5], 1..4);
+/// assert_eq!(bytes(&field), [2, 3, 4]);
+/// assert_eq!(bytes(&field.narrow(1..3)), [3, 4]);
+///
+/// let buf = [1u8, 2, 3];
+/// let shared = share(&BosSlice::new(&buf[..], 2..3));
+/// assert_eq!(shared, [3]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BosSlice<B> {
+    holder: B,
+    range: Range<usize>,
+}
+
+impl<B> BosSlice<B> {
+    /// Creates a view of the elements in `range` of the data of `holder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if its start is
+    /// greater than its end.
+    #[inline]
+    #[track_caller]
+    pub fn new<T>(holder: B, range: Range<usize>) -> Self
+    where
+        B: Bos<[T]>,
+    {
+        let len = bos_of::<[T], B>(&holder).len();
+        assert_range(&range, len);
+        BosSlice { holder, range }
+    }
+
+    /// Creates a view of the elements in `range` of the data of `holder`,
+    /// or returns `None` if the range is out of bounds or if its start is
+    /// greater than its end.
+    #[inline]
+    pub fn try_new<T>(holder: B, range: Range<usize>) -> Option<Self>
+    where
+        B: Bos<[T]>,
+    {
+        let len = bos_of::<[T], B>(&holder).len();
+        if range.start <= range.end && range.end <= len {
+            Some(BosSlice { holder, range })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the range within the data of the holder.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+
+    /// Narrows the view to `range`, relative to the current view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the current view or if
+    /// its start is greater than its end.
+    #[inline]
+    #[track_caller]
+    pub fn narrow(self, range: Range<usize>) -> Self {
+        assert_range(&range, self.range.len());
+        let start = self.range.start;
+        BosSlice {
+            holder: self.holder,
+            range: start + range.start..start + range.end,
+        }
+    }
+}
+
+#[track_caller]
+fn assert_range(range: &Range<usize>, len: usize) {
+    assert!(
+        range.start <= range.end,
+        "slice index starts at {} but ends at {}",
+        range.start,
+        range.end
+    );
+    assert!(
+        range.end <= len,
+        "range end index {} out of range for slice of length {len}",
+        range.end
+    );
+}
+
+impl<T, B: Bos<[T]>> Bos<[T]> for BosSlice<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder).map_same(|s| &s[this.range()])
+    }
+}
+
+impl<T, B: BorrowsFrom<[T]>> BorrowsFrom<[T]> for BosSlice<B> {}
+impl<T, B: SharesWith<[T]>> SharesWith<[T]> for BosSlice<B> {}
+impl<T, B: StableBos<[T]>> StableBos<[T]> for BosSlice<B> {}
+
+impl<'a, T: 'a, B: Shares<'a, [T]>> Shares<'a, [T]> for BosSlice<B> {
+    #[inline]
+    fn share(&self) -> &'a [T] {
+        &self.holder.share()[self.range()]
+    }
+}
+
+impl<T, B: TryBos<[T]>> TryBos<[T]> for BosSlice<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder).map(|r| r.map_same(|s| &s[this.range()]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, B: Bos<[T]>> crate::BosToOwned<[T]> for BosSlice<B> {}
@@ -0,0 +1,171 @@
+use crate::{bos_of, internal::Map, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+use core::{fmt, ops::Range};
+
+/// An adapter exposing a substring of the data of a holder.
+///
+/// This is the string counterpart of [`BosSlice`](crate::BosSlice):
+/// a `BosSubstr<B>` holds a `B: Bos<str>` along with a byte range, and
+/// implements [`Bos<str>`] by indexing the string slice borrowed or shared
+/// from the holder with the range. Sharing is preserved: if `B` shares
+/// `str` for `'a`, then so does the adapter.
+///
+/// The range is checked on construction to be in bounds and to lie on
+/// `char` boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, BosSubstr};
+///
+/// fn token<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let source = String::from("let x = 42;");
+/// let ident = BosSubstr::try_new(source, 4..5).unwrap();
+/// assert_eq!(token(&ident), "x");
+/// assert_eq!(ident.as_full(), "let x = 42;");
+///
+/// let source = "héllo";
+/// assert!(BosSubstr::try_new(source, 0..2).is_err());
+/// assert_eq!(token(&BosSubstr::try_new(source, 0..3).unwrap()), "hé");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BosSubstr<B> {
+    holder: B,
+    range: Range<usize>,
+}
+
+/// The error returned when a [`BosSubstr`] cannot be created.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SubstrError {
+    /// The range is out of bounds or its start is greater than its end.
+    OutOfBounds,
+    /// An end of the range is not on a `char` boundary.
+    NotCharBoundary,
+}
+
+impl fmt::Display for SubstrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SubstrError::OutOfBounds => "byte range out of bounds",
+            SubstrError::NotCharBoundary => "byte range not on char boundaries",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SubstrError {}
+
+fn check(s: &str, range: &Range<usize>) -> Result<(), SubstrError> {
+    if range.start > range.end || range.end > s.len() {
+        Err(SubstrError::OutOfBounds)
+    } else if !s.is_char_boundary(range.start) || !s.is_char_boundary(range.end) {
+        Err(SubstrError::NotCharBoundary)
+    } else {
+        Ok(())
+    }
+}
+
+impl<B: Bos<str>> BosSubstr<B> {
+    /// Creates a view of the bytes in `range` of the string slice of `holder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_new`](Self::try_new) would fail.
+    #[inline]
+    #[track_caller]
+    pub fn new(holder: B, range: Range<usize>) -> Self {
+        match Self::try_new(holder, range) {
+            Ok(substr) => substr,
+            Err(e) => panic!("invalid substring: {e}"),
+        }
+    }
+
+    /// Creates a view of the bytes in `range` of the string slice of `holder`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range is out of bounds, if its start is
+    /// greater than its end, or if it does not lie on `char` boundaries.
+    #[inline]
+    pub fn try_new(holder: B, range: Range<usize>) -> Result<Self, SubstrError> {
+        check(bos_of::<str, B>(&holder), &range)?;
+        Ok(BosSubstr { holder, range })
+    }
+
+    /// Returns the whole string slice of the holder.
+    #[inline]
+    pub fn as_full(&self) -> &str {
+        bos_of::<str, B>(&self.holder)
+    }
+
+    /// Narrows the view to `range`, relative to the current view.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range is out of bounds of the current view,
+    /// if its start is greater than its end, or if it does not lie on `char`
+    /// boundaries.
+    #[inline]
+    pub fn slice(self, range: Range<usize>) -> Result<Self, SubstrError> {
+        check(&self.as_full()[self.range()], &range)?;
+        let start = self.range.start;
+        Ok(BosSubstr {
+            holder: self.holder,
+            range: start + range.start..start + range.end,
+        })
+    }
+}
+
+impl<B> BosSubstr<B> {
+    /// Returns the byte range within the string slice of the holder.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B: Bos<str>> Bos<str> for BosSubstr<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder).map_same(|s| &s[this.range()])
+    }
+}
+
+impl<B: BorrowsFrom<str>> BorrowsFrom<str> for BosSubstr<B> {}
+impl<B: SharesWith<str>> SharesWith<str> for BosSubstr<B> {}
+impl<B: StableBos<str>> StableBos<str> for BosSubstr<B> {}
+
+impl<'a, B: Shares<'a, str>> Shares<'a, str> for BosSubstr<B> {
+    #[inline]
+    fn share(&self) -> &'a str {
+        &self.holder.share()[self.range()]
+    }
+}
+
+impl<B: TryBos<str>> TryBos<str> for BosSubstr<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder).map(|r| r.map_same(|s| &s[this.range()]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Bos<str>> crate::BosToOwned<str> for BosSubstr<B> {}
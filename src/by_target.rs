@@ -0,0 +1,220 @@
+use crate::{borrow_or_share_hash, bos_of, BorrowsFrom, Bos, Shares, SharesWith, StableBos};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// A wrapper comparing and hashing a holder by its data of type `T`.
+///
+/// A `ByTarget<B, T>` implements [`PartialEq`], [`Eq`], [`PartialOrd`],
+/// [`Ord`], and [`Hash`] by delegating to the `&T` borrowed or shared from
+/// the holder, so that `ByTarget<String, str>` and `ByTarget<&str, str>`
+/// compare equal and hash identically when their strings do. It also
+/// compares with a plain `&T`.
+///
+/// Since hashing is consistent with `T`, [`Borrow<T>`] is implemented too,
+/// which allows looking up a `ByTarget<B, T>` key in a [`HashMap`] or
+/// a [`BTreeMap`] by a `&T`.
+///
+/// The holder type may differ between the operands of the comparisons,
+/// but the target type may not.
+///
+/// [`HashMap`]: std::collections::HashMap
+/// [`BTreeMap`]: std::collections::BTreeMap
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::ByTarget;
+/// use std::collections::HashMap;
+///
+/// let owned = ByTarget::<_, str>::new(String::from("key"));
+/// let shared = ByTarget::<_, str>::new("key");
+/// assert!(owned == shared);
+/// assert!(owned == "key");
+///
+/// let mut map = HashMap::new();
+/// map.insert(owned, 1);
+/// assert_eq!(map.get("key"), Some(&1));
+/// ```
+#[repr(transparent)]
+pub struct ByTarget<B, T: ?Sized> {
+    holder: B,
+    marker: PhantomData<fn() -> *const T>,
+}
+
+/// A wrapper hashing a holder by its data of type `T`.
+///
+/// This is another name for [`ByTarget`], whose [`Hash`] implementation
+/// hashes the data with [`borrow_or_share_hash`], for code that only
+/// cares about hashing. Since a hashed key must usually be compared as
+/// well, the comparison traits are implemented consistently.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::HashBos;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(HashBos::<_, [u8]>::new(vec![1, 2, 3]));
+/// assert!(set.contains(&[1, 2, 3][..]));
+/// ```
+pub type HashBos<B, T> = ByTarget<B, T>;
+
+impl<B, T: ?Sized> ByTarget<B, T> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        ByTarget {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+
+    /// Borrows the data by which the holder is compared.
+    #[inline]
+    pub fn as_target(&self) -> &T
+    where
+        B: Bos<T>,
+    {
+        bos_of::<T, B>(&self.holder)
+    }
+}
+
+impl<B, T: ?Sized> From<B> for ByTarget<B, T> {
+    #[inline]
+    fn from(holder: B) -> Self {
+        ByTarget::new(holder)
+    }
+}
+
+impl<B: Clone, T: ?Sized> Clone for ByTarget<B, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ByTarget::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, T: ?Sized> Copy for ByTarget<B, T> {}
+
+impl<B: Default, T: ?Sized> Default for ByTarget<B, T> {
+    #[inline]
+    fn default() -> Self {
+        ByTarget::new(B::default())
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + fmt::Debug> fmt::Debug for ByTarget<B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByTarget").field(&self.as_target()).finish()
+    }
+}
+
+impl<B, C, T> PartialEq<ByTarget<C, T>> for ByTarget<B, T>
+where
+    B: Bos<T>,
+    C: Bos<T>,
+    T: ?Sized + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &ByTarget<C, T>) -> bool {
+        self.as_target() == other.as_target()
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + PartialEq> PartialEq<&T> for ByTarget<B, T> {
+    #[inline]
+    fn eq(&self, other: &&T) -> bool {
+        self.as_target() == *other
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + Eq> Eq for ByTarget<B, T> {}
+
+impl<B, C, T> PartialOrd<ByTarget<C, T>> for ByTarget<B, T>
+where
+    B: Bos<T>,
+    C: Bos<T>,
+    T: ?Sized + PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ByTarget<C, T>) -> Option<Ordering> {
+        self.as_target().partial_cmp(other.as_target())
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + PartialOrd> PartialOrd<&T> for ByTarget<B, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &&T) -> Option<Ordering> {
+        self.as_target().partial_cmp(*other)
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + Ord> Ord for ByTarget<B, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_target().cmp(other.as_target())
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized + Hash> Hash for ByTarget<B, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        borrow_or_share_hash::<T, B, H>(&self.holder, state)
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized> Borrow<T> for ByTarget<B, T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self.as_target()
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized> Bos<T> for ByTarget<B, T> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder)
+    }
+}
+
+impl<B: BorrowsFrom<T>, T: ?Sized> BorrowsFrom<T> for ByTarget<B, T> {}
+impl<B: SharesWith<T>, T: ?Sized> SharesWith<T> for ByTarget<B, T> {}
+impl<B: StableBos<T>, T: ?Sized> StableBos<T> for ByTarget<B, T> {}
+
+impl<'a, B: Shares<'a, T>, T: ?Sized> Shares<'a, T> for ByTarget<B, T> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self.holder.share()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B> crate::BosToOwned<T> for ByTarget<B, T>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+    B: crate::BosToOwned<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.holder.into_owned_target()
+    }
+}
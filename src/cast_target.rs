@@ -0,0 +1,147 @@
+use crate::{internal::Map, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+use core::{fmt, marker::PhantomData};
+use ref_cast::RefCast;
+
+/// An adapter re-typing the data of a holder as a transparent wrapper.
+///
+/// A `CastTarget<B, W>` holds a `B: Bos<T>`, where `W` is a wrapper
+/// implementing [`RefCast<From = T>`](RefCast), and implements [`Bos<W>`]
+/// by casting the `&T` borrowed or shared from the holder with
+/// [`RefCast::ref_cast`]. Sharing is preserved: if `B` shares `T` for `'a`,
+/// then the adapter shares `W` for `'a` too.
+///
+/// The wrapper type `W` must be `'static`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, CastTarget};
+/// use ref_cast::RefCast;
+///
+/// #[derive(RefCast)]
+/// #[repr(transparent)]
+/// struct Ident(str);
+///
+/// fn ident<'i, 'o, B: BorrowOrShare<'i, 'o, Ident>>(b: &'i B) -> &'o Ident {
+///     b.borrow_or_share()
+/// }
+///
+/// let name = CastTarget::<_, Ident>::new(String::from("main"));
+/// assert_eq!(&ident(&name).0, "main");
+/// ```
+#[repr(transparent)]
+pub struct CastTarget<B, W: ?Sized> {
+    holder: B,
+    marker: PhantomData<fn() -> *const W>,
+}
+
+impl<B, W: ?Sized> CastTarget<B, W> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        CastTarget {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B, W: ?Sized> From<B> for CastTarget<B, W> {
+    #[inline]
+    fn from(holder: B) -> Self {
+        CastTarget::new(holder)
+    }
+}
+
+impl<B: Clone, W: ?Sized> Clone for CastTarget<B, W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        CastTarget::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, W: ?Sized> Copy for CastTarget<B, W> {}
+
+impl<B: fmt::Debug, W: ?Sized> fmt::Debug for CastTarget<B, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CastTarget").field(&self.holder).finish()
+    }
+}
+
+impl<B, W> Bos<W> for CastTarget<B, W>
+where
+    B: Bos<W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+    type Ref<'this> = <B::Ref<'this> as Map<W::From>>::Mapped<W> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder).map(W::ref_cast)
+    }
+}
+
+impl<B, W> BorrowsFrom<W> for CastTarget<B, W>
+where
+    B: BorrowsFrom<W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+}
+
+impl<B, W> SharesWith<W> for CastTarget<B, W>
+where
+    B: SharesWith<W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+}
+
+impl<B, W> StableBos<W> for CastTarget<B, W>
+where
+    B: StableBos<W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+}
+
+impl<'a, B, W> Shares<'a, W> for CastTarget<B, W>
+where
+    B: Shares<'a, W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+    #[inline]
+    fn share(&self) -> &'a W {
+        W::ref_cast(self.holder.share())
+    }
+}
+
+impl<B, W> TryBos<W> for CastTarget<B, W>
+where
+    B: TryBos<W::From>,
+    W: RefCast + ?Sized + 'static,
+{
+    type Ref<'this> = <B::Ref<'this> as Map<W::From>>::Mapped<W> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder).map(|r| r.map(W::ref_cast))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B, W> crate::BosToOwned<W> for CastTarget<B, W>
+where
+    B: Bos<W::From>,
+    W: RefCast + alloc::borrow::ToOwned + ?Sized + 'static,
+{
+}
@@ -0,0 +1,98 @@
+use crate::{Bos, BosToOwned, Shares, SharesWith, StableBos, TryBos};
+use alloc::collections::VecDeque;
+
+/// A reference to a [`VecDeque<T>`] that is known to be contiguous.
+///
+/// [`VecDeque<T>`] implements [`Bos<[T]>`](Bos) by panicking if it is not
+/// contiguous. A `Contiguous<'a, T>` can only be created from a contiguous
+/// deque, and the deque cannot be modified while it is borrowed, so its
+/// [`Bos<[T]>`](Bos) implementation never panics. It shares the contents
+/// of the deque for `'a`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Contiguous};
+/// use std::collections::VecDeque;
+///
+/// fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [i32]>>(b: &'i B) -> &'o [i32] {
+///     b.borrow_or_share()
+/// }
+///
+/// let mut deque = VecDeque::from(vec![2, 3]);
+/// deque.push_front(1);
+///
+/// let contiguous = Contiguous::make(&mut deque);
+/// assert_eq!(slice(&contiguous), [1, 2, 3]);
+/// ```
+#[derive(Debug)]
+pub struct Contiguous<'a, T>(&'a VecDeque<T>);
+
+impl<'a, T> Contiguous<'a, T> {
+    /// Wraps the deque if it is contiguous, or returns `None` otherwise.
+    #[inline]
+    pub fn new(deque: &'a VecDeque<T>) -> Option<Self> {
+        match deque.as_slices() {
+            (_, []) => Some(Contiguous(deque)),
+            _ => None,
+        }
+    }
+
+    /// Makes the deque contiguous and wraps it.
+    #[inline]
+    pub fn make(deque: &'a mut VecDeque<T>) -> Self {
+        deque.make_contiguous();
+        Contiguous(deque)
+    }
+
+    /// Returns the contents of the deque as a single slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.0.as_slices().0
+    }
+
+    /// Returns the wrapped deque.
+    #[inline]
+    pub fn get(&self) -> &'a VecDeque<T> {
+        self.0
+    }
+}
+
+impl<T> Clone for Contiguous<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Contiguous<'_, T> {}
+
+impl<'a, T> Bos<[T]> for Contiguous<'a, T> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_slice()
+    }
+}
+
+impl<T> SharesWith<[T]> for Contiguous<'_, T> {}
+impl<T> StableBos<[T]> for Contiguous<'_, T> {}
+
+impl<'a, T> Shares<'a, [T]> for Contiguous<'a, T> {
+    #[inline]
+    fn share(&self) -> &'a [T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> TryBos<[T]> for Contiguous<'a, T> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.as_slice())
+    }
+}
+
+impl<T: Clone> BosToOwned<[T]> for Contiguous<'_, T> {}
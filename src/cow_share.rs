@@ -0,0 +1,65 @@
+use crate::Boo;
+use alloc::borrow::{Cow, ToOwned};
+use core::borrow::Borrow;
+
+/// A holder that may share its data with a longer lifetime, depending on
+/// which variant it is at runtime.
+///
+/// The [`Bos`] implementation on [`Cow<'a, B>`] borrows from
+/// `*self` even when it is [`Cow::Borrowed`], because the variant is not
+/// known statically. This trait returns `Ok(&'a B)` for the borrowed
+/// variant instead, and `Err(&B)` borrowed from `*self` for the owned one,
+/// so that the borrowed case can be handled without cloning.
+///
+/// This is an unstable API, available with the `nightly` feature only,
+/// and may change or go away. The stable API is unchanged: the [`Bos`]
+/// implementations on [`Cow<'_, B>`] and [`Boo<'_, B>`] still borrow.
+///
+/// [`Bos`]: crate::Bos
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::CowShare;
+/// use std::borrow::Cow;
+///
+/// fn first_word<'a>(text: &Cow<'a, str>) -> Option<&'a str> {
+///     let text = text.borrow_or_share_cow().ok()?;
+///     text.split(' ').next()
+/// }
+///
+/// assert_eq!(first_word(&Cow::Borrowed("hello world")), Some("hello"));
+/// assert_eq!(first_word(&Cow::Owned("hello world".into())), None);
+/// ```
+pub trait CowShare<'o, B: ?Sized> {
+    /// Returns the data shared for `'o` if it is borrowed,
+    /// or borrowed from `*self` for `'i` if it is owned.
+    fn borrow_or_share_cow<'i>(&'i self) -> Result<&'o B, &'i B>;
+
+    /// Returns the data shared for `'o` if it is borrowed,
+    /// or `None` if it is owned.
+    #[inline]
+    fn try_share(&self) -> Option<&'o B> {
+        self.borrow_or_share_cow().ok()
+    }
+}
+
+impl<'o, B: ?Sized + ToOwned> CowShare<'o, B> for Cow<'o, B> {
+    #[inline]
+    fn borrow_or_share_cow<'i>(&'i self) -> Result<&'o B, &'i B> {
+        match self {
+            Cow::Borrowed(b) => Ok(b),
+            Cow::Owned(o) => Err(o.borrow()),
+        }
+    }
+}
+
+impl<'o, B: ?Sized + ToOwned> CowShare<'o, B> for Boo<'o, B> {
+    #[inline]
+    fn borrow_or_share_cow<'i>(&'i self) -> Result<&'o B, &'i B> {
+        match self.shared() {
+            Some(b) => Ok(b),
+            None => Err(self.as_target()),
+        }
+    }
+}
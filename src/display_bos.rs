@@ -0,0 +1,45 @@
+use crate::{borrow_or_share_fmt, Bos};
+use core::fmt;
+
+/// A wrapper implementing [`Display`](fmt::Display) on any [`Bos<str>`] holder.
+///
+/// The string slice borrowed or shared from the holder is formatted with
+/// [`borrow_or_share_fmt`], so flags such as width and alignment are
+/// honored. The wrapper is useful for passing a holder to code that
+/// expects a [`Display`](fmt::Display) type, such as `to_string`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::DisplayBos;
+///
+/// let owned = DisplayBos(String::from("hello"));
+/// let shared = DisplayBos("hello");
+/// assert_eq!(format!("{owned:>7}"), "  hello");
+/// assert_eq!(owned.to_string(), shared.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DisplayBos<B>(pub B);
+
+impl<B> DisplayBos<B> {
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B> From<B> for DisplayBos<B> {
+    #[inline]
+    fn from(b: B) -> Self {
+        DisplayBos(b)
+    }
+}
+
+impl<B: Bos<str>> fmt::Display for DisplayBos<B> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        borrow_or_share_fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{bos_of, Bos};
+use core::{ffi::CStr, fmt, marker::PhantomData, str};
+
+#[cfg(feature = "std")]
+use std::{ffi::OsStr, path::Path};
+
+/// A wrapper formatting the data of type `T` borrowed or shared from a holder.
+///
+/// Unlike [`DisplayBos`](crate::DisplayBos), which only supports string
+/// slices, `DisplayVia<B, T>` implements the formatting traits that make
+/// sense for each of the following targets:
+///
+/// | Target    | [`Display`]                   | [`Debug`]         | [`LowerHex`], [`UpperHex`] |
+/// |-----------|-------------------------------|-------------------|----------------------------|
+/// | `str`     | as is                         | quoted            | -                          |
+/// | `[u8]`    | -                             | hexadecimal       | hexadecimal                |
+/// | `CStr`    | lossy UTF-8                   | quoted            | -                          |
+/// | `Path`    | via [`Path::display`]         | quoted            | -                          |
+/// | `OsStr`   | lossy UTF-8                   | quoted            | -                          |
+///
+/// `Path` and `OsStr` are only supported with the `std` feature.
+///
+/// [`Display`]: fmt::Display
+/// [`Debug`]: fmt::Debug
+/// [`LowerHex`]: fmt::LowerHex
+/// [`UpperHex`]: fmt::UpperHex
+/// [`Path::display`]: std::path::Path::display
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::DisplayVia;
+///
+/// let bytes = DisplayVia::<_, [u8]>::new(vec![0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(format!("{bytes:?}"), "deadbeef");
+/// assert_eq!(format!("{bytes:X}"), "DEADBEEF");
+///
+/// let s = DisplayVia::<_, str>::new(String::from("hello"));
+/// assert_eq!(format!("{s} {s:?}"), "hello \"hello\"");
+/// ```
+#[repr(transparent)]
+pub struct DisplayVia<B, T: ?Sized> {
+    holder: B,
+    marker: PhantomData<fn() -> *const T>,
+}
+
+impl<B, T: ?Sized> DisplayVia<B, T> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        DisplayVia {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+
+    /// Borrows the data to be formatted.
+    #[inline]
+    pub fn as_target(&self) -> &T
+    where
+        B: Bos<T>,
+    {
+        bos_of::<T, B>(&self.holder)
+    }
+}
+
+impl<B, T: ?Sized> From<B> for DisplayVia<B, T> {
+    #[inline]
+    fn from(holder: B) -> Self {
+        DisplayVia::new(holder)
+    }
+}
+
+impl<B: Clone, T: ?Sized> Clone for DisplayVia<B, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        DisplayVia::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, T: ?Sized> Copy for DisplayVia<B, T> {}
+
+fn fmt_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+    for byte in bytes {
+        if upper {
+            write!(f, "{byte:02X}")?;
+        } else {
+            write!(f, "{byte:02x}")?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_lossy(mut bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    loop {
+        match str::from_utf8(bytes) {
+            Ok(s) => return f.write_str(s),
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                // The bytes up to `valid_up_to` are valid UTF-8, so this never
+                // falls back to the default.
+                f.write_str(str::from_utf8(valid).unwrap_or_default())?;
+                f.write_str("\u{FFFD}")?;
+                match e.error_len() {
+                    Some(len) => bytes = &rest[len..],
+                    None => return Ok(()),
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_fmt_via {
+    ($($(#[$attr:meta])* $trait:ident for $target:ty => |$t:ident, $f:ident| $fmt:expr)*) => {
+        $(
+            $(#[$attr])*
+            impl<B: Bos<$target>> fmt::$trait for DisplayVia<B, $target> {
+                #[inline]
+                fn fmt(&self, $f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let $t = self.as_target();
+                    $fmt
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_via! {
+    Display for str => |t, f| fmt::Display::fmt(t, f)
+    Debug for str => |t, f| fmt::Debug::fmt(t, f)
+
+    Debug for [u8] => |t, f| fmt_hex(t, f, false)
+    LowerHex for [u8] => |t, f| fmt_hex(t, f, false)
+    UpperHex for [u8] => |t, f| fmt_hex(t, f, true)
+
+    Display for CStr => |t, f| fmt_lossy(t.to_bytes(), f)
+    Debug for CStr => |t, f| fmt::Debug::fmt(t, f)
+
+    #[cfg(feature = "std")]
+    Display for Path => |t, f| fmt::Display::fmt(&t.display(), f)
+    #[cfg(feature = "std")]
+    Debug for Path => |t, f| fmt::Debug::fmt(t, f)
+
+    #[cfg(feature = "std")]
+    Display for OsStr => |t, f| fmt::Display::fmt(&t.to_string_lossy(), f)
+    #[cfg(feature = "std")]
+    Debug for OsStr => |t, f| fmt::Debug::fmt(t, f)
+}
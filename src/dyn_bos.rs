@@ -0,0 +1,85 @@
+use crate::{BorrowOrShare, Bos, BorrowsFrom};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// An object-safe version of [`Bos`] with borrowing semantics only.
+///
+/// [`Bos`] is not object safe because of its generic associated type,
+/// so it cannot be used to store holders of different types behind
+/// a common trait object. This trait fills the gap: it is implemented
+/// on all (sized) types that implement [`Bos`], and `dyn DynBos<T>`
+/// in turn implements [`Bos<T>`], borrowing from `*self`. So does
+/// `Box<dyn DynBos<T>>` with the `alloc` feature, so that boxed holders
+/// can be passed on to code bounded on [`Bos<T>`] as they are.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, DynBos};
+/// use std::rc::Rc;
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str> + ?Sized>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let holders: Vec<Box<dyn DynBos<str>>> = vec![
+///     Box::new(String::from("owned")),
+///     Box::new("static"),
+///     Box::new(Rc::<str>::from("counted")),
+/// ];
+///
+/// let strs: Vec<&str> = holders.iter().map(|b| as_str(&**b)).collect();
+/// assert_eq!(strs, ["owned", "static", "counted"]);
+///
+/// let strs: Vec<&str> = holders.iter().map(as_str).collect();
+/// assert_eq!(strs, ["owned", "static", "counted"]);
+/// ```
+pub trait DynBos<T: ?Sized> {
+    /// Borrows from `*self` or from behind a reference it holds.
+    ///
+    /// The returned reference always borrows from `*self`.
+    fn dyn_borrow(&self) -> &T;
+}
+
+impl<T: ?Sized, B: Bos<T>> DynBos<T> for B {
+    #[inline]
+    fn dyn_borrow(&self) -> &T {
+        <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(self)
+    }
+}
+
+macro_rules! impl_bos_for_dyn {
+    ($($ty:ty => |$this:ident| $get:expr;)*) => {
+        $(
+            impl<T: ?Sized> Bos<T> for $ty {
+                type Ref<'this> = &'this T where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share($this: &Self) -> Self::Ref<'_> {
+                    $get
+                }
+            }
+
+            impl<T: ?Sized> BorrowsFrom<T> for $ty {}
+
+            #[cfg(feature = "alloc")]
+            impl<T: ?Sized + alloc::borrow::ToOwned> crate::BosToOwned<T> for $ty {}
+        )*
+    };
+}
+
+impl_bos_for_dyn! {
+    dyn DynBos<T> + '_ => |this| this.dyn_borrow();
+    dyn DynBos<T> + Send + '_ => |this| this.dyn_borrow();
+    dyn DynBos<T> + Sync + '_ => |this| this.dyn_borrow();
+    dyn DynBos<T> + Send + Sync + '_ => |this| this.dyn_borrow();
+}
+
+#[cfg(feature = "alloc")]
+impl_bos_for_dyn! {
+    Box<dyn DynBos<T> + '_> => |this| (**this).dyn_borrow();
+    Box<dyn DynBos<T> + Send + '_> => |this| (**this).dyn_borrow();
+    Box<dyn DynBos<T> + Sync + '_> => |this| (**this).dyn_borrow();
+    Box<dyn DynBos<T> + Send + Sync + '_> => |this| (**this).dyn_borrow();
+}
@@ -0,0 +1,131 @@
+use crate::{bos_of, BorrowsFrom, Bos, StableBos, TryBos};
+
+/// A holder that is one of two holder types.
+///
+/// This is useful for storing either of two kinds of holders in the same
+/// place, such as an interned `&'static str` or a runtime `String`. Its
+/// [`Bos<T>`] implementation forwards to whichever variant is present.
+/// Since the variant is only known at runtime, it always borrows from
+/// `*self`, even if both holders share. Like the implementation on
+/// [`Result<A, E>`], it requires the target to be `'static`.
+///
+/// It converts from and into [`Result<L, R>`], mapping [`Ok`] to
+/// [`Left`](Self::Left) and [`Err`] to [`Right`](Self::Right).
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, EitherBos};
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// let interned: Text<EitherBos<&'static str, String>> = Text(EitherBos::Left("hello"));
+/// let runtime: Text<EitherBos<&'static str, String>> =
+///     Text(EitherBos::Right(String::from("hello")));
+/// assert_eq!(interned.as_str(), runtime.as_str());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EitherBos<L, R> {
+    /// The left holder.
+    Left(L),
+    /// The right holder.
+    Right(R),
+}
+
+impl<L, R> EitherBos<L, R> {
+    /// Returns `true` if the holder is [`Left`](Self::Left).
+    #[inline]
+    pub fn is_left(&self) -> bool {
+        matches!(self, EitherBos::Left(_))
+    }
+
+    /// Returns `true` if the holder is [`Right`](Self::Right).
+    #[inline]
+    pub fn is_right(&self) -> bool {
+        matches!(self, EitherBos::Right(_))
+    }
+
+    /// Maps the left holder with a function, leaving a right one untouched.
+    #[inline]
+    pub fn map_left<M, F: FnOnce(L) -> M>(self, f: F) -> EitherBos<M, R> {
+        match self {
+            EitherBos::Left(l) => EitherBos::Left(f(l)),
+            EitherBos::Right(r) => EitherBos::Right(r),
+        }
+    }
+
+    /// Maps the right holder with a function, leaving a left one untouched.
+    #[inline]
+    pub fn map_right<S, F: FnOnce(R) -> S>(self, f: F) -> EitherBos<L, S> {
+        match self {
+            EitherBos::Left(l) => EitherBos::Left(l),
+            EitherBos::Right(r) => EitherBos::Right(f(r)),
+        }
+    }
+}
+
+impl<L, R> From<Result<L, R>> for EitherBos<L, R> {
+    #[inline]
+    fn from(res: Result<L, R>) -> Self {
+        match res {
+            Ok(l) => EitherBos::Left(l),
+            Err(r) => EitherBos::Right(r),
+        }
+    }
+}
+
+impl<L, R> From<EitherBos<L, R>> for Result<L, R> {
+    #[inline]
+    fn from(either: EitherBos<L, R>) -> Self {
+        match either {
+            EitherBos::Left(l) => Ok(l),
+            EitherBos::Right(r) => Err(r),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static, L: Bos<T>, R: Bos<T>> Bos<T> for EitherBos<L, R> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        match this {
+            EitherBos::Left(l) => bos_of::<T, L>(l),
+            EitherBos::Right(r) => bos_of::<T, R>(r),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static, L: Bos<T>, R: Bos<T>> BorrowsFrom<T> for EitherBos<L, R> {}
+impl<T: ?Sized + 'static, L: StableBos<T>, R: StableBos<T>> StableBos<T> for EitherBos<L, R> {}
+
+impl<T: ?Sized + 'static, L: Bos<T>, R: Bos<T>> TryBos<T> for EitherBos<L, R> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(Bos::borrow_or_share(this))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, L, R> crate::BosToOwned<T> for EitherBos<L, R>
+where
+    T: ?Sized + alloc::borrow::ToOwned + 'static,
+    L: crate::BosToOwned<T>,
+    R: crate::BosToOwned<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        match self {
+            EitherBos::Left(l) => l.into_owned_target(),
+            EitherBos::Right(r) => r.into_owned_target(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Bos, Shares, SharesWith, StableBos, TryBos};
+
+/// A reference to an array of which only a prefix is filled.
+///
+/// This is useful for parsers over fixed-size stack buffers, where only
+/// the first `len` elements of the buffer are in use. [`Bos<[T]>`](Bos) is
+/// implemented by sharing the filled prefix for `'a`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Filled};
+///
+/// fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+///     b.borrow_or_share()
+/// }
+///
+/// let buf = [1, 2, 3, 0, 0, 0, 0, 0];
+/// let filled = Filled::new(&buf, 3);
+/// assert_eq!(slice(&filled), [1, 2, 3]);
+/// ```
+#[derive(Debug)]
+pub struct Filled<'a, T, const N: usize> {
+    buf: &'a [T; N],
+    len: usize,
+}
+
+impl<'a, T, const N: usize> Filled<'a, T, N> {
+    /// Creates a view of the first `len` elements of `buf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than `N`.
+    #[inline]
+    #[track_caller]
+    pub fn new(buf: &'a [T; N], len: usize) -> Self {
+        assert!(len <= N, "filled length {len} exceeds capacity {N}");
+        Filled { buf, len }
+    }
+
+    /// Returns the filled prefix as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the whole buffer.
+    #[inline]
+    pub fn buf(&self) -> &'a [T; N] {
+        self.buf
+    }
+
+    /// Returns the number of filled elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements are filled.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T, const N: usize> Clone for Filled<'_, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for Filled<'_, T, N> {}
+
+impl<'a, T, const N: usize> Bos<[T]> for Filled<'a, T, N> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_slice()
+    }
+}
+
+impl<T, const N: usize> SharesWith<[T]> for Filled<'_, T, N> {}
+impl<T, const N: usize> StableBos<[T]> for Filled<'_, T, N> {}
+
+impl<'a, T, const N: usize> Shares<'a, [T]> for Filled<'a, T, N> {
+    #[inline]
+    fn share(&self) -> &'a [T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, const N: usize> TryBos<[T]> for Filled<'a, T, N> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, const N: usize> crate::BosToOwned<[T]> for Filled<'_, T, N> {}
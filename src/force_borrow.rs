@@ -0,0 +1,97 @@
+use crate::{BorrowOrShare, BorrowsFrom, Bos, TryBorrowOrShare, TryBos};
+
+/// A wrapper that borrows from `*self` even if the holder shares.
+///
+/// `ForceBorrow<B>` implements [`Bos<T>`] wherever `B` does, but its
+/// [`Ref`](Bos::Ref) is always `&'this T`, so the returned reference never
+/// outlives the wrapper, even when `B` is a `&'a T`. This is useful for
+/// types that must ensure that nothing they hand out escapes them,
+/// e.g., to revalidate data at some point.
+///
+/// The target must be `'static`: as it does not appear in the type of the
+/// wrapper, it would otherwise not be known to outlive `'this`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, ForceBorrow};
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// let text = Text(ForceBorrow("hello"));
+/// assert_eq!(text.as_str(), "hello");
+/// ```
+///
+/// The data cannot be shared for `'a`:
+///
+/// ```compile_fail
+/// use borrow_or_share::{BorrowOrShare, ForceBorrow};
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// fn share<'a>(text: &Text<ForceBorrow<&'a str>>) -> &'a str {
+///     text.as_str()
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ForceBorrow<B>(pub B);
+
+impl<B> ForceBorrow<B> {
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B> From<B> for ForceBorrow<B> {
+    #[inline]
+    fn from(b: B) -> Self {
+        ForceBorrow(b)
+    }
+}
+
+impl<T: ?Sized + 'static, B: Bos<T>> Bos<T> for ForceBorrow<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(&this.0)
+    }
+}
+
+impl<T: ?Sized + 'static, B: Bos<T>> BorrowsFrom<T> for ForceBorrow<B> {}
+
+impl<T: ?Sized + 'static, B: TryBos<T>> TryBos<T> for ForceBorrow<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        <B as TryBorrowOrShare<'_, '_, T>>::try_borrow_or_share(&this.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B> crate::BosToOwned<T> for ForceBorrow<B>
+where
+    T: ?Sized + alloc::borrow::ToOwned + 'static,
+    B: crate::BosToOwned<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.0.into_owned_target()
+    }
+}
@@ -0,0 +1,112 @@
+/// Implements [`Bos`](crate::Bos) on a wrapper by forwarding to a field.
+///
+/// The macro takes a wrapper type with its generic parameters in braces,
+/// the target, the field to forward to, and the type of that field:
+///
+/// ```text
+/// forward_bos!({Params} Wrapper => Target, via .field: FieldType);
+/// forward_bos!({Params} Wrapper => Target, via .field: FieldType where Bounds);
+/// ```
+///
+/// It implements [`Bos<Target>`](crate::Bos) on the wrapper with the
+/// [`Ref`](crate::Bos::Ref) type of the field, so that sharing is preserved,
+/// as well as [`TryBos`](crate::TryBos), [`Shares`](crate::Shares), and each
+/// of the marker traits [`BorrowsFrom`](crate::BorrowsFrom),
+/// [`SharesWith`](crate::SharesWith), and [`StableBos`](crate::StableBos),
+/// on the condition that the field type implements it. The field may be
+/// named or positional, e.g. `.0`, and extra bounds may be given in
+/// a `where` clause.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{forward_bos, BorrowOrShare};
+///
+/// struct Name<T> {
+///     inner: T,
+///     id: u32,
+/// }
+///
+/// struct Tagged<B, M>(B, M);
+///
+/// forward_bos!({T} Name<T> => str, via .inner: T);
+/// forward_bos!({B, M} Tagged<B, M> => [u8], via .0: B where M: Copy);
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// // The data is shared for `'a`, as with `&'a str`.
+/// fn share<'a>(name: &Name<&'a str>) -> &'a str {
+///     as_str(name)
+/// }
+///
+/// let name = Name { inner: String::from("alice"), id: 1 };
+/// assert_eq!(as_str(&name), "alice");
+/// assert_eq!(share(&Name { inner: "bob", id: 2 }), "bob");
+/// ```
+#[macro_export]
+macro_rules! forward_bos {
+    (
+        $({$($params:tt)*})? $ty:ty => $target:ty,
+        via . $field:tt : $fty:ty $(where $($bounds:tt)*)?
+    ) => {
+        impl $(<$($params)*>)? $crate::Bos<$target> for $ty
+        where
+            $fty: $crate::Bos<$target>,
+            $($($bounds)*)?
+        {
+            type Ref<'this> = <$fty as $crate::Bos<$target>>::Ref<'this> where Self: 'this;
+
+            #[inline]
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                <$fty as $crate::Bos<$target>>::borrow_or_share(&this.$field)
+            }
+        }
+
+        impl $(<$($params)*>)? $crate::TryBos<$target> for $ty
+        where
+            $fty: $crate::TryBos<$target>,
+            $($($bounds)*)?
+        {
+            type Ref<'this> = <$fty as $crate::TryBos<$target>>::Ref<'this> where Self: 'this;
+
+            #[inline]
+            fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+                <$fty as $crate::TryBos<$target>>::try_borrow_or_share(&this.$field)
+            }
+        }
+
+        impl $(<$($params)*>)? $crate::BorrowsFrom<$target> for $ty
+        where
+            $fty: $crate::BorrowsFrom<$target>,
+            $($($bounds)*)?
+        {
+        }
+
+        impl $(<$($params)*>)? $crate::SharesWith<$target> for $ty
+        where
+            $fty: $crate::SharesWith<$target>,
+            $($($bounds)*)?
+        {
+        }
+
+        impl<'__a $(, $($params)*)?> $crate::Shares<'__a, $target> for $ty
+        where
+            $fty: $crate::Shares<'__a, $target>,
+            $($($bounds)*)?
+        {
+            #[inline]
+            fn share(&self) -> &'__a $target {
+                <$fty as $crate::Shares<'__a, $target>>::share(&self.$field)
+            }
+        }
+
+        impl $(<$($params)*>)? $crate::StableBos<$target> for $ty
+        where
+            $fty: $crate::StableBos<$target>,
+            $($($bounds)*)?
+        {
+        }
+    };
+}
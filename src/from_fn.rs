@@ -0,0 +1,104 @@
+use crate::{BorrowsFrom, Bos, TryBos};
+use core::fmt;
+
+/// An adapter borrowing data from a value with an accessor function.
+///
+/// Created by [`from_fn`]. A `FromFn<S, T>` holds a value of type `S`
+/// along with a function from `&S` to `&T`, and implements [`Bos<T>`]
+/// by calling the function on the value, borrowing from `*self`.
+///
+/// The accessor is a plain function pointer rather than a closure type,
+/// so that the adapter can be named and stored without boxing. Closures
+/// that capture nothing coerce to it.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{from_fn, BorrowOrShare};
+///
+/// struct Config {
+///     name: String,
+///     port: u16,
+/// }
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let config = Config { name: String::from("server"), port: 8080 };
+/// let name = from_fn(config, |c| c.name.as_str());
+/// assert_eq!(as_str(&name), "server");
+/// assert_eq!(name.get().port, 8080);
+/// ```
+pub struct FromFn<S, T: ?Sized> {
+    value: S,
+    get: for<'a> fn(&'a S) -> &'a T,
+}
+
+/// Creates an adapter borrowing data from `value` with `get`.
+///
+/// See [`FromFn`] for details.
+#[inline]
+pub const fn from_fn<S, T: ?Sized>(value: S, get: for<'a> fn(&'a S) -> &'a T) -> FromFn<S, T> {
+    FromFn { value, get }
+}
+
+impl<S, T: ?Sized> FromFn<S, T> {
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &S {
+        &self.value
+    }
+
+    /// Returns the accessor function.
+    #[inline]
+    pub fn func(&self) -> for<'a> fn(&'a S) -> &'a T {
+        self.get
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.value
+    }
+}
+
+impl<S: Clone, T: ?Sized> Clone for FromFn<S, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        from_fn(self.value.clone(), self.get)
+    }
+}
+
+impl<S: Copy, T: ?Sized> Copy for FromFn<S, T> {}
+
+impl<S: fmt::Debug, T: ?Sized> fmt::Debug for FromFn<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromFn")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, T: ?Sized> Bos<T> for FromFn<S, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        (this.get)(&this.value)
+    }
+}
+
+impl<S, T: ?Sized> BorrowsFrom<T> for FromFn<S, T> {}
+
+impl<S, T: ?Sized> TryBos<T> for FromFn<S, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some((this.get)(&this.value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T: ?Sized + alloc::borrow::ToOwned> crate::BosToOwned<T> for FromFn<S, T> {}
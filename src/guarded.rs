@@ -0,0 +1,183 @@
+use crate::{BorrowOrShare, BorrowsFrom, Bos, TryBos};
+use core::{
+    cell::{Ref as CellRef, RefCell},
+    ops::Deref,
+};
+
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+/// A guard through which data of type `T` may be accessed.
+///
+/// This is implemented on [`&T`](reference), as well as on the guards
+/// returned by [`RefCell::borrow`], [`Mutex::lock`], and [`RwLock::read`]
+/// when the guarded value implements [`Bos<T>`].
+///
+/// [`Mutex::lock`]: std::sync::Mutex::lock
+/// [`RwLock::read`]: std::sync::RwLock::read
+pub trait BosGuard<T: ?Sized> {
+    /// Returns a reference to the data behind the guard.
+    fn as_target(&self) -> &T;
+}
+
+impl<T: ?Sized> BosGuard<T> for &T {
+    #[inline]
+    fn as_target(&self) -> &T {
+        self
+    }
+}
+
+/// A trait for borrowing data through a guard.
+///
+/// Some holders, such as [`RefCell<B>`], can only lend their data through
+/// a guard that must be kept alive while the data is accessed. They cannot
+/// implement [`Bos`], whose [`Ref`](Bos::Ref) may only be a reference, so
+/// this trait is provided separately: its [`Guard`](Self::Guard) may be
+/// any type implementing [`BosGuard<T>`]. [`Bos`] and [`BorrowOrShare`]
+/// are unaffected, and keep returning plain references.
+///
+/// This crate implements the trait on [`RefCell<B>`], [`Mutex<B>`], and
+/// [`RwLock<B>`] where `B: Bos<T>`. Lock poisoning is ignored.
+///
+/// [`Mutex<B>`]: std::sync::Mutex
+/// [`RwLock<B>`]: std::sync::RwLock
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BosGuard, GuardedBos};
+/// use std::cell::RefCell;
+///
+/// fn len<B: GuardedBos<str>>(b: &B) -> usize {
+///     B::borrow_guarded(b).as_target().len()
+/// }
+///
+/// let cell = RefCell::new(String::from("hello"));
+/// assert_eq!(len(&cell), 5);
+///
+/// cell.borrow_mut().push_str(", world");
+/// assert_eq!(len(&cell), 12);
+/// ```
+pub trait GuardedBos<T: ?Sized> {
+    /// The resulting guard type.
+    type Guard<'this>: BosGuard<T>
+    where
+        Self: 'this;
+
+    /// Borrows from `*this` through a guard.
+    fn borrow_guarded(this: &Self) -> Self::Guard<'_>;
+}
+
+macro_rules! impl_guarded_bos {
+    ($($(#[$attr:meta])? $holder:ident => $guard:ident, |$this:ident| $get:expr)*) => {
+        $(
+            $(#[$attr])?
+            impl<T: ?Sized, B: Bos<T> + ?Sized> BosGuard<T> for $guard<'_, B> {
+                #[inline]
+                fn as_target(&self) -> &T {
+                    <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(self)
+                }
+            }
+
+            $(#[$attr])?
+            impl<T: ?Sized, B: Bos<T> + ?Sized> GuardedBos<T> for $holder<B> {
+                type Guard<'this> = $guard<'this, B> where Self: 'this;
+
+                #[inline]
+                fn borrow_guarded($this: &Self) -> Self::Guard<'_> {
+                    $get
+                }
+            }
+        )*
+    };
+}
+
+impl_guarded_bos! {
+    RefCell => CellRef, |this| this.borrow()
+    #[cfg(feature = "std")]
+    Mutex => MutexGuard, |this| this.lock().unwrap_or_else(PoisonError::into_inner)
+    #[cfg(feature = "std")]
+    RwLock => RwLockReadGuard, |this| this.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A [`Ref`](core::cell::Ref) guard wrapped to implement [`Bos<T>`].
+///
+/// Unlike [`GuardedBos`], which is implemented on the [`RefCell<T>`] itself,
+/// this wrapper lets data behind an explicitly taken guard be passed to code
+/// bounded on [`Bos`]. It borrows from `*self`, so the returned reference
+/// cannot outlive the guard.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, RefCellRef};
+/// use std::cell::RefCell;
+///
+/// fn len<'i, 'o, B: BorrowOrShare<'i, 'o, Vec<u8>>>(b: &'i B) -> usize {
+///     b.borrow_or_share().len()
+/// }
+///
+/// let cell = RefCell::new(vec![1, 2, 3]);
+/// let guard = RefCellRef::borrow(&cell);
+/// assert_eq!(len(&guard), 3);
+/// ```
+#[derive(Debug)]
+pub struct RefCellRef<'a, T: ?Sized>(CellRef<'a, T>);
+
+impl<'a, T: ?Sized> RefCellRef<'a, T> {
+    /// Immutably borrows the value in the cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn borrow(cell: &'a RefCell<T>) -> Self {
+        RefCellRef(cell.borrow())
+    }
+
+    /// Unwraps the guard.
+    #[inline]
+    pub fn into_inner(self) -> CellRef<'a, T> {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> From<CellRef<'a, T>> for RefCellRef<'a, T> {
+    #[inline]
+    fn from(guard: CellRef<'a, T>) -> Self {
+        RefCellRef(guard)
+    }
+}
+
+impl<T: ?Sized> Deref for RefCellRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> Bos<T> for RefCellRef<'_, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        &**this
+    }
+}
+
+impl<T: ?Sized> BorrowsFrom<T> for RefCellRef<'_, T> {}
+
+impl<T: ?Sized> TryBos<T> for RefCellRef<'_, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(&**this)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + alloc::borrow::ToOwned> crate::BosToOwned<T> for RefCellRef<'_, T> {}
@@ -0,0 +1,208 @@
+use crate::{BorrowsFrom, Bos, TryBos};
+use core::{
+    cell::{Cell, OnceCell},
+    fmt,
+};
+
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// A holder whose data is computed on first access.
+///
+/// A `LazyBos<T, F>` holds an initializer `F` producing a `T`, which is
+/// chosen per instance rather than per `static`. Its [`Bos<T>`]
+/// implementation runs the initializer on the first call, and then
+/// borrows the computed value from `*self` like an owned holder.
+///
+/// This type is not [`Sync`]. See [`SyncLazyBos`] for a thread-safe
+/// variant. Both require Rust 1.70 or later.
+///
+/// # Panics
+///
+/// If the initializer panics, the panic is propagated to the caller,
+/// and any later access panics as well.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, LazyBos};
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, String>>(b: &'i B) -> &'o String {
+///     b.borrow_or_share()
+/// }
+///
+/// let lazy = LazyBos::new(|| "hello".repeat(2));
+/// assert_eq!(LazyBos::get(&lazy), None);
+/// assert_eq!(as_str(&lazy), "hellohello");
+/// assert_eq!(LazyBos::get(&lazy).map(String::len), Some(10));
+/// ```
+pub struct LazyBos<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: Cell<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> LazyBos<T, F> {
+    /// Creates a holder whose data is computed with `f` on first access.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        LazyBos {
+            cell: OnceCell::new(),
+            init: Cell::new(Some(f)),
+        }
+    }
+
+    /// Computes the data if needed, and returns a reference to it.
+    #[inline]
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| match this.init.take() {
+            Some(f) => f(),
+            None => panic!("`LazyBos` instance has previously been poisoned"),
+        })
+    }
+}
+
+impl<T, F> LazyBos<T, F> {
+    /// Returns a reference to the data if it has been computed.
+    #[inline]
+    pub fn get(this: &Self) -> Option<&T> {
+        this.cell.get()
+    }
+}
+
+impl<T: Default> Default for LazyBos<T> {
+    #[inline]
+    fn default() -> Self {
+        LazyBos::new(T::default)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for LazyBos<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyBos").field(&self.cell).finish()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Bos<T> for LazyBos<T, F> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        LazyBos::force(this)
+    }
+}
+
+impl<T, F: FnOnce() -> T> BorrowsFrom<T> for LazyBos<T, F> {}
+
+impl<T, F: FnOnce() -> T> TryBos<T> for LazyBos<T, F> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(LazyBos::force(this))
+    }
+}
+
+/// A thread-safe holder whose data is computed on first access.
+///
+/// This is the [`Sync`] variant of [`LazyBos`], backed by [`OnceLock`].
+/// The initializer is run at most once, even if the data is accessed from
+/// multiple threads at the same time.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, SyncLazyBos};
+/// use std::thread;
+///
+/// fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, Vec<u8>>>(b: &'i B) -> &'o Vec<u8> {
+///     b.borrow_or_share()
+/// }
+///
+/// let lazy = SyncLazyBos::new(|| vec![1, 2, 3]);
+/// thread::scope(|s| {
+///     s.spawn(|| assert_eq!(bytes(&lazy).len(), 3));
+///     s.spawn(|| assert_eq!(bytes(&lazy).len(), 3));
+/// });
+/// ```
+#[cfg(feature = "std")]
+pub struct SyncLazyBos<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: Mutex<Option<F>>,
+}
+
+#[cfg(feature = "std")]
+impl<T, F: FnOnce() -> T> SyncLazyBos<T, F> {
+    /// Creates a holder whose data is computed with `f` on first access.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        SyncLazyBos {
+            cell: OnceLock::new(),
+            init: Mutex::new(Some(f)),
+        }
+    }
+
+    /// Computes the data if needed, and returns a reference to it.
+    #[inline]
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| {
+            let init = this.init.lock().unwrap_or_else(PoisonError::into_inner).take();
+            match init {
+                Some(f) => f(),
+                None => panic!("`SyncLazyBos` instance has previously been poisoned"),
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, F> SyncLazyBos<T, F> {
+    /// Returns a reference to the data if it has been computed.
+    #[inline]
+    pub fn get(this: &Self) -> Option<&T> {
+        this.cell.get()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Default> Default for SyncLazyBos<T> {
+    #[inline]
+    fn default() -> Self {
+        SyncLazyBos::new(T::default)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, F> fmt::Debug for SyncLazyBos<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SyncLazyBos").field(&self.cell).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, F: FnOnce() -> T> Bos<T> for SyncLazyBos<T, F> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        SyncLazyBos::force(this)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, F: FnOnce() -> T> BorrowsFrom<T> for SyncLazyBos<T, F> {}
+
+#[cfg(feature = "std")]
+impl<T, F: FnOnce() -> T> TryBos<T> for SyncLazyBos<T, F> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(SyncLazyBos::force(this))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, F: FnOnce() -> T> crate::BosToOwned<T> for LazyBos<T, F> {}
+
+#[cfg(feature = "std")]
+impl<T: Clone, F: FnOnce() -> T> crate::BosToOwned<T> for SyncLazyBos<T, F> {}
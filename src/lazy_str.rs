@@ -0,0 +1,145 @@
+use crate::{Bos, BorrowsFrom};
+use alloc::string::{String, ToString};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// A string that is either owned or borrowed for `'a`.
+///
+/// This is much like [`Cow<'a, str>`], except that it can be created from
+/// [`fmt::Arguments`] without allocating when there is nothing to format.
+///
+/// Like [`Cow<'a, str>`], it compares and hashes by content regardless of
+/// the variant.
+///
+/// Its [`Bos<str>`] implementation borrows from `*self` in both cases,
+/// because the variant is not known statically. Use [`LazyStr::shared`]
+/// to get at the longer-lived reference in the borrowed case.
+///
+/// [`Cow<'a, str>`]: alloc::borrow::Cow
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, LazyStr};
+///
+/// let name = "world";
+/// let greeting = LazyStr::from(format_args!("hello"));
+/// assert_eq!(greeting.shared(), Some("hello"));
+///
+/// let greeting = LazyStr::from(format_args!("hello, {name}"));
+/// assert_eq!(greeting.shared(), None);
+/// assert_eq!(greeting.borrow_or_share(), "hello, world");
+/// ```
+#[derive(Clone, Debug)]
+pub enum LazyStr<'a> {
+    /// An owned string.
+    Owned(String),
+    /// A string borrowed for `'a`.
+    Borrowed(&'a str),
+}
+
+impl<'a> LazyStr<'a> {
+    /// Returns the string as borrowed for `'a`, or `None` if it is owned.
+    #[inline]
+    pub fn shared(&self) -> Option<&'a str> {
+        match *self {
+            LazyStr::Owned(_) => None,
+            LazyStr::Borrowed(s) => Some(s),
+        }
+    }
+
+    /// Returns the string as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            LazyStr::Owned(s) => s,
+            LazyStr::Borrowed(s) => s,
+        }
+    }
+
+    /// Converts the string into a `String`, allocating if it is borrowed.
+    #[inline]
+    pub fn into_owned(self) -> String {
+        match self {
+            LazyStr::Owned(s) => s,
+            LazyStr::Borrowed(s) => s.into(),
+        }
+    }
+}
+
+impl<'b> PartialEq<LazyStr<'b>> for LazyStr<'_> {
+    #[inline]
+    fn eq(&self, other: &LazyStr<'b>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for LazyStr<'_> {}
+
+impl<'b> PartialOrd<LazyStr<'b>> for LazyStr<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &LazyStr<'b>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl Ord for LazyStr<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for LazyStr<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Bos<str> for LazyStr<'_> {
+    type Ref<'this> = &'this str where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_str()
+    }
+}
+
+impl BorrowsFrom<str> for LazyStr<'_> {}
+
+impl From<String> for LazyStr<'_> {
+    #[inline]
+    fn from(s: String) -> Self {
+        LazyStr::Owned(s)
+    }
+}
+
+impl<'a> From<&'a str> for LazyStr<'a> {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        LazyStr::Borrowed(s)
+    }
+}
+
+impl From<fmt::Arguments<'_>> for LazyStr<'_> {
+    /// Borrows the formatted string if it is known at compile time,
+    /// and formats it into an owned string otherwise.
+    #[inline]
+    fn from(args: fmt::Arguments<'_>) -> Self {
+        match args.as_str() {
+            Some(s) => LazyStr::Borrowed(s),
+            None => LazyStr::Owned(args.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for LazyStr<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
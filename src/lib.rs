@@ -1,6 +1,7 @@
 #![warn(missing_docs, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
 #![no_std]
 
 //! Traits for either borrowing or sharing data.
@@ -130,14 +131,59 @@
 //! all types that implement [`Bos`]. It also works the other way round
 //! because [`Bos`] is a supertrait of [`BorrowOrShare`].
 //!
+//! Alternatively, bound on [`BorrowFromSelf`] and call its `borrowed` method
+//! when you only need a reference tied to the borrow of `self`.
+//!
+//! As both traits have a function named `borrow_or_share`, a call like
+//! `String::borrow_or_share(&s)` is ambiguous. Use method call syntax,
+//! a fully qualified path like `<String as Bos<str>>::borrow_or_share`,
+//! or the free function [`borrow_or_share()`] instead. The free functions
+//! also make it easy to specify the target type with turbofish syntax,
+//! e.g., `borrow_or_share::<str, _>(&s)` or [`bos_of::<str, _>(&s)`](bos_of).
+//!
 //! This crate provides [`Bos`] (and [`BorrowOrShare`]) implementations
 //! on [`&T`](reference), [`&mut T`](reference), [`[T; N]`](array),
-//! [`Vec<T>`], [`String`], [`CString`], [`OsString`], [`PathBuf`],
-//! [`Box<T>`], [`Cow<'_, B>`], [`Rc<T>`], and [`Arc<T>`]. If some of
-//! these are out of scope, consider putting extra trait bounds in your
-//! code, preferably on a function that constructs your type.
+//! [`Cell<[T]>`], [`Cell<[T; N]>`], [`Vec<T>`], [`VecDeque<T>`] (panicking
+//! if not contiguous), [`String`], [`CString`], [`OsString`], [`PathBuf`],
+//! [`Box<T>`], [`Cow<'_, B>`], [`Rc<T>`], [`Arc<T>`], [`Cursor<T>`]
+//! (forwarding to the inner buffer), [`ManuallyDrop<B>`] (forwarding to
+//! the inner holder), [`Pin<&T>`] and [`Pin<&mut T>`]
+//! (like the references they hold), [`Result<A, E>`] (borrowing from
+//! whichever variant is present, for any `'static` target), and
+//! [`Infallible`] (for any `'static` target, as it can never be
+//! constructed). The [`EitherBos<L, R>`](EitherBos) enum holds one of two
+//! holder types, and with the `alloc` feature, the [`Boo<'a, T>`](Boo)
+//! enum offers a holder that is either borrowed or owned without the
+//! clone-on-write API of [`Cow<'_, B>`]. If some of these are out of scope,
+//! consider putting extra trait bounds in your code, preferably on
+//! a function that constructs your type.
+//!
+//! References to some of these types are also projected to the borrowed
+//! form, sharing the lifetime of the reference. For example, `&'a Vec<T>`
+//! implements `Bos<[T]>` in addition to `Bos<Vec<T>>`, so that the
+//! returned `&'a [T]` may outlive the holder. These projections are:
+//!
+//! - `&'a [T; N]` to `[T]`, and fallibly to `str` via [`TryBos`] if `T` is `u8`
+//! - `&'a Vec<T>` to `[T]`
+//! - `&'a String` to `str`
+//! - `&'a Box<[T]>` to `[T]`, and `&'a Box<str>` to `str`
+//! - `&'a Cow<'_, B>` to `B`
+//! - `&'a OsString` to `OsStr`
+//! - `&'a (dyn AsRef<str> + 'a)` to `str`, via [`AsRef::as_ref`]
+//! - `&'a LazyLock<T>` to `T`, as well as `&'a LazyLock<Vec<T>>` to `[T]` and
+//!   `&'a LazyLock<String>` to `str`, initializing the value if needed
+//!
+//! Similarly, `Rc<Vec<T>>` and `Arc<Vec<T>>` implement `Bos<[T]>`,
+//! while `Rc<String>` and `Arc<String>` implement `Bos<str>`, and
+//! `Rc<str>` and `Arc<str>` implement `Bos<[u8]>`. These
+//! borrow from `*self` as usual.
 //!
 //! [`Cow<'_, B>`]: Cow
+//! [`ManuallyDrop<B>`]: ManuallyDrop
+//! [`Pin<&T>`]: Pin
+//! [`Pin<&mut T>`]: Pin
+//! [`Cell<[T]>`]: Cell
+//! [`Cell<[T; N]>`]: Cell
 //!
 //! You can also implement [`Bos`] on your own type, for example:
 //!
@@ -155,144 +201,2734 @@
 //! }
 //! ```
 //!
+//! If your type implements [`Deref`](core::ops::Deref) and borrows from
+//! `*self`, wrapping it in [`ViaDeref`] or invoking [`bos_for_deref_target!`]
+//! on it saves you the implementation. Similarly, a type that only implements
+//! [`Borrow`] or [`AsRef`] can be wrapped in [`ViaBorrow`] or [`ViaAsRef`].
+//! A wrapper around a holder can forward to it with [`forward_bos!`],
+//! or, with the `derive` feature, with [`#[derive(Bos)]`](macro@Bos).
+//! For a one-off integration with a foreign type, [`from_fn`] pairs a value
+//! with an accessor function. To compare and hash holders by their data,
+//! so that e.g. `String` and `&str` holders are interchangeable as map keys,
+//! wrap them in [`ByTarget`]. To check an invariant of the data once
+//! on construction, wrap them in [`Validated`]. An optional holder with
+//! a static fallback, as is common in configuration, is [`WithDefault`],
+//! and metadata such as a source span can ride along with a holder in
+//! [`Annotated`].
+//! In tests and examples, [`bos_str!`] and [`bos_bytes!`] create sharing
+//! holders from literals.
+//!
+//! # Borrowing versus sharing
+//!
+//! Whether a type borrows or shares is usually only visible in lifetimes.
+//! When you need to tell the two apart at the type level, for example
+//! to offer an extra API only for sharing holders, you can use the
+//! [`BorrowsFrom`] and [`SharesWith`] marker traits. Every [`Bos`]
+//! implementation in this crate that statically either borrows or shares
+//! comes with exactly one of these markers:
+//!
+//! - [`&'a T`](reference) and the projecting implementations on references,
+//!   such as `&'a OsString: Bos<OsStr>`, implement [`SharesWith`].
+//! - Owned types such as `String` and `Vec<T>`, as well as [`&mut T`](reference),
+//!   implement [`BorrowsFrom`].
+//! - Forwarding implementations, such as the one on [`Cursor<T>`],
+//!   implement whichever marker the inner type implements.
+//! - [`ForceBorrow<B>`](ForceBorrow) implements [`BorrowsFrom`] whatever `B`
+//!   does, for when a sharing holder must be made to borrow.
+//! - [`Shared<'a, T>`](Shared), a named [`&'a T`](reference), implements
+//!   [`SharesWith`], and states the intent of sharing in public APIs.
+//!
+//! Bound on [`SharesWith`] if your code relies on the returned reference
+//! being independent of the holder, and on [`BorrowsFrom`] if it must not
+//! outlive the holder. [`Shares<'o, T>`](Shares) goes one step further than
+//! [`SharesWith`] by naming the lifetime of the shared reference. Most code
+//! should bound on neither, and stay generic over both behaviors.
+//!
+//! Orthogonal to these is [`StableBos`], for holders whose returned
+//! reference keeps its address when the holder is moved, which rules out
+//! inline storage such as `[T; N]`.
+//!
+//! ```
+//! use borrow_or_share::{BorrowOrShare, SharesWith};
+//!
+//! struct Text<T>(T);
+//!
+//! impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+//!     fn as_str(&'i self) -> &'o str {
+//!         self.0.borrow_or_share()
+//!     }
+//! }
+//!
+//! impl<T: SharesWith<str>> Text<T> {
+//!     fn is_shared(&self) -> bool {
+//!         true
+//!     }
+//! }
+//!
+//! assert!(Text("hello").is_shared());
+//! ```
+//!
+//! Note that the markers are a documented contract rather than something
+//! the compiler checks, because they are safe to implement.
+//!
+//! # Mutable access
+//!
+//! The [`BosMut`] and [`BorrowOrShareMut`] traits are the mutable
+//! counterparts of [`Bos`] and [`BorrowOrShare`]. They let you write
+//! a single method for mutating data that is either owned or held
+//! by a mutable reference:
+//!
+//! ```
+//! use borrow_or_share::BorrowOrShareMut;
+//!
+//! struct TextMut<T>(T);
+//!
+//! impl<'i, 'o, T: BorrowOrShareMut<'i, 'o, str>> TextMut<T> {
+//!     fn as_mut_str(&'i mut self) -> &'o mut str {
+//!         self.0.borrow_or_share_mut()
+//!     }
+//! }
+//!
+//! let mut text = TextMut(String::from("hello"));
+//! text.as_mut_str().make_ascii_uppercase();
+//! assert_eq!(text.0, "HELLO");
+//!
+//! let mut s = String::from("world");
+//! let mut text = TextMut(s.as_mut_str());
+//! text.as_mut_str().make_ascii_uppercase();
+//! assert_eq!(s, "WORLD");
+//! ```
+//!
+//! Since an exclusive reference cannot be copied out from behind
+//! another reference, the returned reference always borrows from `*self`,
+//! even when `self` holds a `&'a mut T`. The same goes for reading:
+//! `&'a mut [T; N]` and `&'a mut Vec<T>` implement `Bos<[T]>`, and
+//! `&'a mut String` implements `Bos<str>`, by borrowing from `*self`,
+//! as a `&'a [T]` or `&'a str` would alias the exclusive reference still
+//! held. To read the data for `'a`, give up the exclusive access first by
+//! converting the reference into a `&'a [T; N]`, `&'a Vec<T>`, or
+//! `&'a String`, which share.
+//!
+//! This crate provides [`BosMut`] implementations on [`&mut T`](reference)
+//! (including `&mut Vec<T>` to `[T]` and `&mut String` to `str`),
+//! [`[T; N]`](array), [`Vec<T>`], [`String`], [`Box<T>`] (including
+//! `Box<[T]>` and `Box<str>`), and [`Cow<'_, B>`] (via [`Cow::to_mut`]).
+//!
 //! # Limitations
 //!
-//! This crate only provides implementations of [`Bos`] on types that
+//! This crate mostly provides implementations of [`Bos`] on types that
 //! currently implement [`Borrow`] in the standard library, not including
-//! the blanket implementation. If this is too restrictive, feel free
-//! to copy the code pattern from this crate as you wish.
+//! the blanket implementation. If this is too restrictive, invoke
+//! [`impl_bos_via_borrow!`] on your types that implement [`Borrow`],
+//! or feel free to copy the code pattern from this crate as you wish.
+//!
+//! Data behind shared mutability is never projected to plain data.
+//! [`Cell<[T]>`] projects only to `[Cell<T>]`, and likewise a shared slice
+//! of atomics, such as `&'a [AtomicU8]`, only implements `Bos<[AtomicU8]>`
+//! through the implementation on [`&T`](reference), never `Bos<[u8]>`,
+//! since the bytes may be modified while the returned reference is alive.
 //!
 //! [`Borrow`]: core::borrow::Borrow
 //!
+//! # Minimum supported Rust version
+//!
+//! The minimum supported Rust version is 1.65, the first stable release
+//! with generic associated types. A formulation without them, such as
+//! a trait `BosLt<'this, T>` taking the lifetime as a parameter, is not
+//! provided. It could live in a module only compiled on older compilers,
+//! but it would be a separate trait with a parallel set of implementations,
+//! and code bounded on either trait would not accept holders of the other.
+//! Implementations that depend on newer standard library items, such as
+//! those on [`OnceCell`], are enabled automatically on
+//! compilers that support them. The optional integrations with other
+//! crates require the minimum Rust versions of those crates.
+//!
 //! # Crate features
 //!
+//! - `alloc` (enabled by default): Enables [`Bos`] implementations on
+//!   [`Vec<T>`], [`VecDeque<T>`], [`String`], [`CString`], [`Box<T>`],
+//!   [`Cow<'_, B>`], [`Rc<T>`], and [`Arc<T>`]. Disabling it makes
+//!   the crate usable without a global allocator.
 //! - `std` (disabled by default): Enables [`Bos`] implementations on
-//!   [`OsString`] and [`PathBuf`].
+//!   [`OsString`], [`PathBuf`], and [`Cursor<T>`]. Implies `alloc`.
+//! - `glib` (disabled by default): Enables [`Bos`] implementations on
+//!   `glib::GString` and `glib::GStr`, which is useful with `gtk-rs`.
+//! - `camino` (disabled by default): Together with `std`, enables [`Bos`]
+//!   implementations on `camino::Utf8PathBuf` and `camino::Utf8Path`
+//!   with [`Path`] and [`OsStr`] as targets.
+//! - `zerovec` (disabled by default): Enables [`Bos<[u8]>`](Bos)
+//!   implementations on `zerovec::ZeroVec<'_, u8>` and `zerovec::ZeroSlice<u8>`.
+//!   A `ZeroVec<'a, u8>` may either borrow a buffer for `'a` or own one,
+//!   which is only known at runtime, so it borrows from `*self` like
+//!   [`Cow<'_, B>`]. A reference `&'a ZeroVec<'_, u8>` shares for `'a`.
+//!   Use `ZeroVec::as_maybe_borrowed` to get at the borrowed buffer.
+//!   Note that `zerovec` itself requires Rust 1.83.
+//! - `serde` (disabled by default): Enables [`SerializeVia`] and
+//!   [`serialize_bos`], which serialize a holder as its target, e.g.
+//!   `str` as a string and `[u8]` as bytes.
+//! - `ndarray` (disabled by default): Enables [`Bos<[T]>`](Bos) and
+//!   [`TryBos<[T]>`](TryBos) implementations on `ndarray::Array1<T>`, which
+//!   borrows, and `ndarray::ArrayView1<'a, T>`, which shares for `'a`.
+//!   As a 1-D array may not be contiguous, e.g., after slicing it with
+//!   a step, the [`Bos`] implementations panic in that case, like the one on
+//!   [`VecDeque<T>`]. Use the [`TryBos`] implementations to avoid this.
+//! - `bytemuck` (disabled by default): Enables [`PodBytes`], which views
+//!   a slice of plain old data as bytes, preserving sharing.
+//! - `ref-cast` (disabled by default): Enables [`CastTarget`], which
+//!   re-types the data of a holder as a transparent wrapper implementing
+//!   `ref_cast::RefCast`, preserving sharing.
+//! - `derive` (disabled by default): Enables [`#[derive(Bos)]`](macro@Bos),
+//!   which implements [`Bos`] on a struct by forwarding to the field
+//!   holding the data, like [`forward_bos!`].
+//! - `bench` (disabled by default): Enables the benchmarks, run with
+//!   `cargo bench --features bench`. It has no effect on the library.
+//! - `nightly` (disabled by default): Requires a nightly compiler.
+//!   Makes [`Bos`] and [`BorrowOrShare`] `const` traits, with `const`
+//!   implementations on [`&T`](reference), [`&mut T`](reference),
+//!   and [`[T; N]`](array), so that they can be used in `const fn`s.
+//!   Also enables the unstable `CowShare` trait along with `alloc`, which
+//!   shares the data of a borrowed `Cow` for its full lifetime.
+//!
+#![cfg_attr(feature = "nightly", doc = "```")]
+#![cfg_attr(not(feature = "nightly"), doc = "```ignore")]
+//! #![feature(const_trait_impl)]
+//!
+//! use borrow_or_share::{BorrowOrShare, Bos};
+//!
+//! const fn first_byte<B: [const] Bos<[u8]>>(b: &B) -> u8 {
+//!     BorrowOrShare::borrow_or_share(b)[0]
+//! }
+//!
+//! const SLICE: &[u8] = &[3, 4];
+//! const TABLE: [u8; 2] = [first_byte(&[1, 2]), first_byte(&SLICE)];
+//! assert_eq!(TABLE, [1, 3]);
+//! ```
 
+#[cfg(any(feature = "alloc", doc))]
 extern crate alloc;
 #[cfg(any(feature = "std", doc))]
 extern crate std;
 
+mod annotated;
+#[cfg(feature = "alloc")]
+mod boo;
+mod bos_cell;
+mod bos_slice;
+mod bos_substr;
+mod by_target;
+#[cfg(feature = "ref-cast")]
+mod cast_target;
+#[cfg(feature = "alloc")]
+mod contiguous;
+#[cfg(all(feature = "nightly", feature = "alloc"))]
+mod cow_share;
+mod display_bos;
+mod display_via;
+mod dyn_bos;
+mod either_bos;
+mod filled;
+mod force_borrow;
+mod forward_bos;
+mod from_fn;
+mod guarded;
+#[cfg(bos_once_cell)]
+#[allow(clippy::incompatible_msrv)]
+mod lazy_bos;
+#[cfg(feature = "alloc")]
+mod lazy_str;
+mod literal;
+mod nested;
+#[cfg(feature = "bytemuck")]
+mod pod_bytes;
+mod project;
+mod projected;
+#[cfg(feature = "serde")]
+mod serialize_via;
+#[cfg(feature = "std")]
+mod segments;
+mod shared;
+mod utf8_bytes;
+mod validated;
+mod via_as_ref;
+mod via_borrow;
+mod via_deref;
+mod with_default;
+
+pub mod prelude;
+
+pub use annotated::Annotated;
+#[cfg(feature = "derive")]
+pub use borrow_or_share_derive::Bos;
+#[cfg(feature = "alloc")]
+pub use boo::Boo;
+pub use bos_cell::BosCell;
+pub use bos_slice::BosSlice;
+pub use bos_substr::{BosSubstr, SubstrError};
+pub use by_target::{ByTarget, HashBos};
+#[cfg(feature = "ref-cast")]
+pub use cast_target::CastTarget;
+#[cfg(feature = "alloc")]
+pub use contiguous::Contiguous;
+#[cfg(all(feature = "nightly", feature = "alloc"))]
+pub use cow_share::CowShare;
+pub use display_bos::DisplayBos;
+pub use display_via::DisplayVia;
+pub use dyn_bos::DynBos;
+pub use either_bos::EitherBos;
+pub use filled::Filled;
+pub use force_borrow::ForceBorrow;
+pub use from_fn::{from_fn, FromFn};
+pub use guarded::{BosGuard, GuardedBos, RefCellRef};
+#[cfg(bos_once_cell)]
+pub use lazy_bos::LazyBos;
+#[cfg(all(feature = "std", bos_once_cell))]
+pub use lazy_bos::SyncLazyBos;
+#[cfg(feature = "alloc")]
+pub use lazy_str::LazyStr;
+pub use nested::Nested;
+#[cfg(feature = "bytemuck")]
+pub use pod_bytes::PodBytes;
+pub use project::Project;
+pub use projected::Projected;
+#[cfg(feature = "serde")]
+pub use serialize_via::{serialize_bos, SerializeTarget, SerializeVia};
+#[cfg(feature = "std")]
+pub use segments::Segments;
+pub use shared::{Shared, Static};
+pub use utf8_bytes::{NotUtf8, Utf8Bytes};
+pub use validated::{AsciiOnly, NotAscii, Validated, Validator};
+pub use via_as_ref::ViaAsRef;
+pub use via_borrow::ViaBorrow;
+pub use via_deref::ViaDeref;
+pub use with_default::WithDefault;
+
+/// Invokes the given macro with a `const` token in the `nightly` mode,
+/// and with no tokens otherwise.
+///
+/// This keeps the unstable `const` trait syntax away from stable compilers,
+/// which reject it even in code that is configured out.
+macro_rules! with_const {
+    ($m:ident) => {
+        #[cfg(feature = "nightly")]
+        $m! { const }
+        #[cfg(not(feature = "nightly"))]
+        $m! {}
+    };
+}
+
 mod internal {
-    pub trait Ref<T: ?Sized> {
-        fn cast<'a>(self) -> &'a T
-        where
-            Self: 'a;
+    pub trait Sealed {}
+
+    impl<T: ?Sized> Sealed for &T {}
+    impl<T: ?Sized> Sealed for &mut T {}
+
+    macro_rules! map_trait {
+        ($($c:tt)?) => {
+            /// Maps `&'a T` to `&'a U` with a function, for adapters such as
+            /// [`Project`](crate::Project) and [`BosSlice`](crate::BosSlice).
+            pub trait Map<T: ?Sized> {
+                type Mapped<U: ?Sized + 'static>: $($c)? crate::Ref<U>;
+
+                fn map<U: ?Sized + 'static>(self, f: fn(&T) -> &U) -> Self::Mapped<U>;
+
+                fn map_same<F: FnOnce(&T) -> &T>(self, f: F) -> Self;
+            }
+        };
     }
 
-    impl<T: ?Sized> Ref<T> for &T {
+    with_const!(map_trait);
+
+    impl<'a, T: ?Sized> Map<T> for &'a T {
+        type Mapped<U: ?Sized + 'static> = &'a U;
+
         #[inline]
-        fn cast<'a>(self) -> &'a T
-        where
-            Self: 'a,
-        {
-            self
+        fn map<U: ?Sized + 'static>(self, f: fn(&T) -> &U) -> &'a U {
+            f(self)
+        }
+
+        #[inline]
+        fn map_same<F: FnOnce(&T) -> &T>(self, f: F) -> &'a T {
+            f(self)
+        }
+    }
+}
+
+macro_rules! ref_trait {
+    ($($c:tt)?) => {
+        /// The trait bound on [`Bos::Ref`], implemented only on [`&T`](reference).
+        ///
+        /// This trait is sealed and cannot be implemented outside this crate.
+        /// It is public so that code generic over [`Bos`] can name it in bounds
+        /// and call [`cast`](Ref::cast), as the blanket [`BorrowOrShare`]
+        /// implementation does.
+        ///
+        /// # Examples
+        ///
+        /// A helper function like [`BorrowOrShare::borrow_or_share`],
+        /// but returning the first byte of a slice:
+        ///
+        /// ```
+        /// use borrow_or_share::{Bos, Ref};
+        ///
+        /// fn first<'i, 'o, B>(b: &'i B) -> Option<&'o u8>
+        /// where
+        ///     B: Bos<[u8]> + 'i,
+        ///     B::Ref<'i>: 'o,
+        /// {
+        ///     let r: B::Ref<'i> = B::borrow_or_share(b);
+        ///     r.cast().first()
+        /// }
+        ///
+        /// fn share<'a>(b: &&'a [u8]) -> Option<&'a u8> {
+        ///     first(b)
+        /// }
+        ///
+        /// assert_eq!(first(&vec![1, 2]), Some(&1));
+        /// assert_eq!(share(&&[3, 4][..]), Some(&3));
+        /// ```
+        ///
+        /// A wrapper forwarding to the inner holder, which preserves sharing
+        /// by reusing its reference type:
+        ///
+        /// ```
+        /// use borrow_or_share::{BorrowOrShare, Bos};
+        ///
+        /// struct Wrapper<B>(B);
+        ///
+        /// impl<T: ?Sized, B: Bos<T>> Bos<T> for Wrapper<B> {
+        ///     type Ref<'this> = B::Ref<'this> where Self: 'this;
+        ///
+        ///     fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        ///         B::borrow_or_share(&this.0)
+        ///     }
+        /// }
+        ///
+        /// fn share<'a>(w: &Wrapper<&'a str>) -> &'a str {
+        ///     w.borrow_or_share()
+        /// }
+        ///
+        /// assert_eq!(share(&Wrapper("shared")), "shared");
+        /// ```
+        pub $($c)? trait Ref<T: ?Sized>: internal::Sealed + internal::Map<T> {
+            /// Converts the reference into `&'a T`.
+            ///
+            /// This is the identity function on `&'a T`. The bound `Self: 'a`
+            /// ensures that the lifetime of the returned reference does not
+            /// exceed that of the original one.
+            fn cast<'a>(self) -> &'a T
+            where
+                Self: 'a;
+        }
+
+        impl<T: ?Sized> $($c)? Ref<T> for &T {
+            #[inline(always)]
+            fn cast<'a>(self) -> &'a T
+            where
+                Self: 'a,
+            {
+                self
+            }
         }
+    };
+}
+
+with_const!(ref_trait);
+
+/// The trait bound on [`BosMut::RefMut`], implemented only on [`&mut T`](reference).
+///
+/// Like [`Ref`], this trait is sealed and cannot be implemented outside this crate.
+pub trait RefMut<T: ?Sized>: internal::Sealed {
+    /// Converts the reference into `&'a mut T`.
+    ///
+    /// This is the identity function on `&'a mut T`.
+    fn cast_mut<'a>(self) -> &'a mut T
+    where
+        Self: 'a;
+}
+
+impl<T: ?Sized> RefMut<T> for &mut T {
+    #[inline(always)]
+    fn cast_mut<'a>(self) -> &'a mut T
+    where
+        Self: 'a,
+    {
+        self
     }
 }
 
+#[cfg(any(feature = "alloc", doc))]
 use alloc::{
     borrow::{Cow, ToOwned},
     boxed::Box,
+    collections::VecDeque,
     ffi::CString,
     rc::Rc,
     string::String,
     sync::Arc,
     vec::Vec,
 };
+#[cfg(feature = "alloc")]
+use core::borrow::BorrowMut;
+use core::cell::Cell;
+use core::convert::Infallible;
 use core::ffi::CStr;
-use internal::Ref;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::slice::{Chunks, Windows};
+#[cfg(bos_once_cell)]
+use core::cell::OnceCell;
 
 #[cfg(any(feature = "std", doc))]
 use std::{
     ffi::{OsStr, OsString},
+    io::Cursor,
     path::{Path, PathBuf},
 };
+#[cfg(all(feature = "std", bos_lazy_lock))]
+use std::sync::LazyLock;
+#[cfg(all(feature = "std", bos_once_cell))]
+use std::sync::OnceLock;
+
+macro_rules! core_traits {
+    ($($c:tt)?) => {
+        /// A trait for either borrowing or sharing data.
+        ///
+        /// See the [crate-level documentation](crate) for more details.
+        #[cfg_attr(
+            bos_diagnostic,
+            diagnostic::on_unimplemented(
+                message = "`{Self}` can neither borrow nor share `{T}`",
+                label = "cannot either borrow or share `{T}`",
+                note = "use a holder type that implements `Bos<{T}>`, such as `&{T}`, or implement it on `{Self}`"
+            )
+        )]
+        pub $($c)? trait Bos<T: ?Sized> {
+            /// The resulting reference type. May only be `&T`.
+            type Ref<'this>: $($c)? Ref<T>
+            where
+                Self: 'this;
+
+            /// Borrows from `*this` or from behind a reference it holds,
+            /// returning a reference of type [`Self::Ref`].
+            ///
+            /// In the latter case, the returned reference is said to be *shared* with `*this`.
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_>;
+        }
+
+        /// A helper trait for writing "data borrowing or sharing" functions.
+        ///
+        /// See the [crate-level documentation](crate) for more details.
+        #[cfg_attr(
+            bos_diagnostic,
+            diagnostic::on_unimplemented(
+                message = "`{Self}` can neither borrow nor share `{T}`",
+                label = "cannot either borrow or share `{T}`",
+                note = "`BorrowOrShare<'i, 'o, {T}>` is implemented on all types that implement `Bos<{T}>`",
+                note = "where the lifetimes `'i` and `'o` are not in scope, e.g., in an `AsRef` impl, bound on `Bos<{T}>` instead"
+            )
+        )]
+        pub $($c)? trait BorrowOrShare<'i, 'o, T: ?Sized>: Bos<T> {
+            /// Borrows from `*self` or from behind a reference it holds.
+            ///
+            /// In the latter case, the returned reference is said to be *shared* with `*self`.
+            fn borrow_or_share(&'i self) -> &'o T;
+        }
+
+        impl<'i, 'o, T: ?Sized, B> $($c)? BorrowOrShare<'i, 'o, T> for B
+        where
+            B: $([$c])? Bos<T> + ?Sized + 'i,
+            B::Ref<'i>: 'o,
+        {
+            #[inline(always)]
+            fn borrow_or_share(&'i self) -> &'o T {
+                (B::borrow_or_share(self) as B::Ref<'i>).cast()
+            }
+        }
+    };
+}
+
+with_const!(core_traits);
 
-/// A trait for either borrowing or sharing data.
+/// An extension trait providing combinators on top of [`BorrowOrShare`].
 ///
-/// See the [crate-level documentation](crate) for more details.
-pub trait Bos<T: ?Sized> {
-    /// The resulting reference type. May only be `&T`.
-    type Ref<'this>: Ref<T>
+/// This is implemented on all types. Its methods are only callable
+/// where the corresponding [`BorrowOrShare`] bound holds, and they
+/// preserve the output lifetime `'o` just like
+/// [`BorrowOrShare::borrow_or_share`].
+pub trait BorrowOrShareExt {
+    /// Borrows or shares data of type `U`, which is specified with
+    /// turbofish syntax when `Self` implements [`Bos`] for multiple targets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::BorrowOrShareExt;
+    ///
+    /// // `&String` implements both `Bos<String>` and `Bos<str>`.
+    /// fn both<'a>(s: &&'a String) -> (&'a String, &'a str) {
+    ///     (s.borrow_or_share_as::<String>(), s.borrow_or_share_as::<str>())
+    /// }
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(both(&&s), (&s, "hello"));
+    /// ```
+    #[inline]
+    fn borrow_or_share_as<'i, 'o, U>(&'i self) -> &'o U
     where
-        Self: 'this;
+        U: ?Sized,
+        Self: BorrowOrShare<'i, 'o, U>,
+    {
+        <Self as BorrowOrShare<'i, 'o, U>>::borrow_or_share(self)
+    }
 
-    /// Borrows from `*this` or from behind a reference it holds,
-    /// returning a reference of type [`Self::Ref`].
+    /// Borrows or shares data of type `U`, with the target given by
+    /// a [`Target<U>`] hint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::{BorrowOrShareExt, Target};
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(s.bos_with(Target::<str>::new()).len(), 5);
+    /// ```
+    #[inline]
+    fn bos_with<'i, 'o, U>(&'i self, _target: Target<U>) -> &'o U
+    where
+        U: ?Sized,
+        Self: BorrowOrShare<'i, 'o, U>,
+    {
+        <Self as BorrowOrShare<'i, 'o, U>>::borrow_or_share(self)
+    }
+
+    /// Borrows or shares data, returning it only if `pred` holds for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::{BorrowOrShare, BorrowOrShareExt};
+    ///
+    /// struct Text<T>(T);
+    ///
+    /// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+    ///     fn non_empty(&'i self) -> Option<&'o str> {
+    ///         self.0.borrow_or_share_validate(|s: &str| !s.is_empty())
+    ///     }
+    /// }
+    ///
+    /// fn share<'a>(text: &Text<&'a str>) -> Option<&'a str> {
+    ///     text.non_empty()
+    /// }
+    ///
+    /// assert_eq!(share(&Text("hello")), Some("hello"));
+    /// assert_eq!(Text(String::new()).non_empty(), None);
+    /// ```
+    #[inline]
+    fn borrow_or_share_validate<'i, 'o, T, F>(&'i self, pred: F) -> Option<&'o T>
+    where
+        T: ?Sized,
+        Self: BorrowOrShare<'i, 'o, T>,
+        F: FnOnce(&'o T) -> bool,
+    {
+        let r = <Self as BorrowOrShare<'i, 'o, T>>::borrow_or_share(self);
+        if pred(r) {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Borrows or shares data of a [`Copy`] type, returning a copy of it.
     ///
-    /// In the latter case, the returned reference is said to be *shared* with `*this`.
-    fn borrow_or_share(this: &Self) -> Self::Ref<'_>;
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::{BorrowOrShareExt, Bos};
+    ///
+    /// struct Id(u32);
+    ///
+    /// impl Bos<u32> for Id {
+    ///     type Ref<'this> = &'this u32;
+    ///
+    ///     fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+    ///         &this.0
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Id(42).borrow_or_share_copied::<u32>(), 42);
+    /// ```
+    #[inline]
+    fn borrow_or_share_copied<T>(&self) -> T
+    where
+        T: Copy,
+        Self: Bos<T>,
+    {
+        *<Self as BorrowOrShare<'_, '_, T>>::borrow_or_share(self)
+    }
+
+    /// Borrows or shares data of type `T` as bytes, where `T` has
+    /// a [`ByteView`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::BorrowOrShareExt;
+    /// use std::ffi::CString;
+    ///
+    /// fn share<'a>(s: &&'a str) -> &'a [u8] {
+    ///     s.borrow_or_share_bytes()
+    /// }
+    ///
+    /// assert_eq!(share(&"hello"), b"hello");
+    /// assert_eq!(CString::new("hello").unwrap().borrow_or_share_bytes(), b"hello");
+    /// ```
+    #[inline]
+    fn borrow_or_share_bytes<'i, 'o, T>(&'i self) -> &'o [u8]
+    where
+        T: ByteView + ?Sized + 'o,
+        Self: BorrowOrShare<'i, 'o, T>,
+    {
+        <Self as BorrowOrShare<'i, 'o, T>>::borrow_or_share(self).as_byte_slice()
+    }
+
+    /// Borrows or shares a string slice and divides it into two at `mid`,
+    /// returning `None` if `mid` is not on a [`char`] boundary or is
+    /// out of bounds.
+    ///
+    /// This is a non-panicking version of [`str::split_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::{BorrowOrShare, BorrowOrShareExt};
+    ///
+    /// struct Text<T>(T);
+    ///
+    /// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+    ///     fn split_at(&'i self, mid: usize) -> Option<(&'o str, &'o str)> {
+    ///         self.0.borrow_or_share_split_at(mid)
+    ///     }
+    /// }
+    ///
+    /// let text = Text(String::from("héllo"));
+    /// assert_eq!(text.split_at(3), Some(("hé", "llo")));
+    /// assert_eq!(text.split_at(2), None);
+    /// ```
+    #[inline]
+    fn borrow_or_share_split_at<'i, 'o>(&'i self, mid: usize) -> Option<(&'o str, &'o str)>
+    where
+        Self: BorrowOrShare<'i, 'o, str>,
+    {
+        let s = <Self as BorrowOrShare<'i, 'o, str>>::borrow_or_share(self);
+        if s.is_char_boundary(mid) {
+            Some(s.split_at(mid))
+        } else {
+            None
+        }
+    }
+
+    /// Borrows or shares a slice and returns an iterator over `size`
+    /// elements of it at a time, as with [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::BorrowOrShareExt;
+    ///
+    /// fn share<'a>(s: &&'a [i32]) -> Vec<&'a [i32]> {
+    ///     s.borrow_or_share_chunks(2).collect()
+    /// }
+    ///
+    /// assert_eq!(share(&&[1, 2, 3][..]), [&[1, 2][..], &[3]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn borrow_or_share_chunks<'i, 'o, T>(&'i self, size: usize) -> Chunks<'o, T>
+    where
+        T: 'o,
+        Self: BorrowOrShare<'i, 'o, [T]>,
+    {
+        <Self as BorrowOrShare<'i, 'o, [T]>>::borrow_or_share(self).chunks(size)
+    }
+
+    /// Borrows or shares a slice and returns an iterator over all
+    /// contiguous windows of length `size` of it, as with [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use borrow_or_share::BorrowOrShareExt;
+    ///
+    /// let sums: Vec<i32> = vec![1, 2, 3]
+    ///     .borrow_or_share_windows(2)
+    ///     .map(|w| w.iter().sum())
+    ///     .collect();
+    /// assert_eq!(sums, [3, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn borrow_or_share_windows<'i, 'o, T>(&'i self, size: usize) -> Windows<'o, T>
+    where
+        T: 'o,
+        Self: BorrowOrShare<'i, 'o, [T]>,
+    {
+        <Self as BorrowOrShare<'i, 'o, [T]>>::borrow_or_share(self).windows(size)
+    }
 }
 
-/// A helper trait for writing "data borrowing or sharing" functions.
+/// A type that can be viewed as a byte slice.
 ///
-/// See the [crate-level documentation](crate) for more details.
-pub trait BorrowOrShare<'i, 'o, T: ?Sized>: Bos<T> {
-    /// Borrows from `*self` or from behind a reference it holds.
-    ///
-    /// In the latter case, the returned reference is said to be *shared* with `*self`.
-    fn borrow_or_share(&'i self) -> &'o T;
+/// This is used by [`BorrowOrShareExt::borrow_or_share_bytes`], and is
+/// implemented on `[u8]`, `str`, and [`CStr`] (without the nul terminator).
+/// With the `std` feature, it is also implemented on [`OsStr`] and [`Path`],
+/// using their platform-specific encoding, on Rust 1.74 or later.
+///
+/// [`OsStr`]: std::ffi::OsStr
+/// [`Path`]: std::path::Path
+pub trait ByteView {
+    /// Returns the bytes of `self`.
+    fn as_byte_slice(&self) -> &[u8];
 }
 
-impl<'i, 'o, T: ?Sized, B> BorrowOrShare<'i, 'o, T> for B
-where
-    B: Bos<T> + ?Sized + 'i,
-    B::Ref<'i>: 'o,
-{
+impl ByteView for [u8] {
     #[inline]
-    fn borrow_or_share(&'i self) -> &'o T {
-        (B::borrow_or_share(self) as B::Ref<'i>).cast()
+    fn as_byte_slice(&self) -> &[u8] {
+        self
     }
 }
 
-impl<'a, T: ?Sized> Bos<T> for &'a T {
-    type Ref<'this> = &'a T where Self: 'this;
+impl ByteView for str {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
 
+impl ByteView for CStr {
     #[inline]
-    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
-        this
+    fn as_byte_slice(&self) -> &[u8] {
+        self.to_bytes()
     }
 }
 
-macro_rules! impl_bos {
-    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty)*) => {
-        $(
-            $(#[$attr])?
-            impl $(<$($params)*>)? Bos<$target> for $ty {
-                type Ref<'this> = &'this $target where Self: 'this;
+#[cfg(all(feature = "std", bos_os_str_bytes))]
+#[allow(clippy::incompatible_msrv)]
+impl ByteView for OsStr {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self.as_encoded_bytes()
+    }
+}
 
-                #[inline]
-                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
-                    this
-                }
-            }
-        )*
-    };
+#[cfg(all(feature = "std", bos_os_str_bytes))]
+#[allow(clippy::incompatible_msrv)]
+impl ByteView for Path {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        self.as_os_str().as_encoded_bytes()
+    }
 }
 
-impl_bos! {
-    // A blanket impl would show up everywhere in the
-    // documentation of a dependent crate, which is noisy.
-    // So we're omitting it for the moment.
-    // {T: ?Sized} T => T
+impl<B: ?Sized> BorrowOrShareExt for B {}
 
-    {T: ?Sized} &mut T => T
+/// A helper trait for borrowing data from `*self`, regardless of sharing.
+///
+/// This is implemented on all types that implement [`Bos`], and gives
+/// a name to the common case where the returned reference may be tied
+/// to the borrow of `self`, e.g., in an [`AsRef`] implementation.
+/// [`Bos<T>`] is equally usable as a bound there, but [`borrowed`]
+/// can be called with method syntax without any lifetimes in sight.
+///
+/// [`borrowed`]: Self::borrowed
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::BorrowFromSelf;
+///
+/// struct Text<T>(T);
+///
+/// impl<T: BorrowFromSelf<str>> AsRef<str> for Text<T> {
+///     fn as_ref(&self) -> &str {
+///         self.0.borrowed()
+///     }
+/// }
+///
+/// assert_eq!(Text(String::from("owned")).as_ref(), "owned");
+/// assert_eq!(Text("shared").as_ref(), "shared");
+/// ```
+pub trait BorrowFromSelf<T: ?Sized>: Bos<T> {
+    /// Borrows from `*self` or from behind a reference it holds,
+    /// for no longer than `self` is borrowed.
+    fn borrowed(&self) -> &T;
+}
 
-    {T, const N: usize} [T; N] => [T]
-    {T} Vec<T> => [T]
+impl<T: ?Sized, B: Bos<T> + ?Sized> BorrowFromSelf<T> for B {
+    #[inline]
+    fn borrowed(&self) -> &T {
+        <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(self)
+    }
+}
 
-    String => str
-    CString => CStr
+/// A trait for mutably borrowing data that is either owned or held by a mutable reference.
+///
+/// See the [crate-level documentation](crate#mutable-access) for more details.
+pub trait BosMut<T: ?Sized>: Bos<T> {
+    /// The resulting mutable reference type. May only be `&mut T`.
+    type RefMut<'this>: RefMut<T>
+    where
+        Self: 'this;
 
-    #[cfg(feature = "std")]
-    OsString => OsStr
-    #[cfg(feature = "std")]
-    PathBuf => Path
+    /// Mutably borrows from `*this` or from behind a mutable reference it holds,
+    /// returning a reference of type [`Self::RefMut`].
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_>;
+}
 
-    {T: ?Sized} Box<T> => T
-    {B: ?Sized + ToOwned} Cow<'_, B> => B
+/// A helper trait for writing "mutable data borrowing" functions.
+///
+/// See the [crate-level documentation](crate#mutable-access) for more details.
+pub trait BorrowOrShareMut<'i, 'o, T: ?Sized>: BosMut<T> {
+    /// Mutably borrows from `*self` or from behind a mutable reference it holds.
+    fn borrow_or_share_mut(&'i mut self) -> &'o mut T;
+}
 
-    {T: ?Sized} Rc<T> => T
-    {T: ?Sized} Arc<T> => T
+impl<'i, 'o, T: ?Sized, B> BorrowOrShareMut<'i, 'o, T> for B
+where
+    B: BosMut<T> + ?Sized + 'i,
+    B::RefMut<'i>: 'o,
+{
+    #[inline(always)]
+    fn borrow_or_share_mut(&'i mut self) -> &'o mut T {
+        (B::borrow_or_share_mut(self) as B::RefMut<'i>).cast_mut()
+    }
+}
+
+/// A trait for either borrowing or sharing data that may be absent.
+///
+/// This is implemented on every standard library type that this crate
+/// implements [`Bos`] on, always returning `Some`, as well as on holders that only sometimes
+/// contain data, namely [`Option<B>`], [`OnceCell<B>`], and [`OnceLock<B>`]
+/// where `B` implements [`Bos`]. These forward to `B`, preserving its
+/// sharing behavior.
+///
+/// Some holders whose data may not be representable as the target are also
+/// covered: [`&[u8; N]`](array) shares its bytes as a `str` if they are
+/// valid UTF-8, while [`OsStr`] and [`OsString`] borrow their contents as
+/// a `str` under the same condition, and `&'a` references to them share it.
+///
+/// [`OsStr`]: std::ffi::OsStr
+/// [`OsString`]: std::ffi::OsString
+///
+/// A blanket implementation on all types that implement [`Bos`] is
+/// deliberately not provided, because it would rule out the above
+/// implementations on foreign holders. If you implement [`Bos`] on your own
+/// type and want it to be usable as a [`TryBos`], implement both.
+///
+/// [`OnceCell<B>`]: core::cell::OnceCell
+/// [`OnceLock<B>`]: std::sync::OnceLock
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::TryBorrowOrShare;
+///
+/// struct Name<T>(T);
+///
+/// impl<'i, 'o, T: TryBorrowOrShare<'i, 'o, str>> Name<T> {
+///     fn get(&'i self) -> Option<&'o str> {
+///         self.0.try_borrow_or_share()
+///     }
+/// }
+///
+/// fn share<'a>(name: &Name<Option<&'a str>>) -> Option<&'a str> {
+///     name.get()
+/// }
+///
+/// assert_eq!(Name(String::from("owned")).get(), Some("owned"));
+/// assert_eq!(share(&Name(Some("shared"))), Some("shared"));
+/// assert_eq!(Name(None::<String>).get(), None);
+/// ```
+pub trait TryBos<T: ?Sized> {
+    /// The resulting reference type. May only be `&T`.
+    type Ref<'this>: Ref<T>
+    where
+        Self: 'this;
+
+    /// Borrows from `*this` or from behind a reference it holds,
+    /// returning `None` if there is no data to borrow or share.
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>>;
+}
+
+/// A helper trait for writing fallible "data borrowing or sharing" functions.
+///
+/// See [`TryBos`] for more details.
+pub trait TryBorrowOrShare<'i, 'o, T: ?Sized>: TryBos<T> {
+    /// Borrows from `*self` or from behind a reference it holds,
+    /// returning `None` if there is no data to borrow or share.
+    fn try_borrow_or_share(&'i self) -> Option<&'o T>;
+}
+
+impl<'i, 'o, T: ?Sized, B> TryBorrowOrShare<'i, 'o, T> for B
+where
+    B: TryBos<T> + ?Sized + 'i,
+    B::Ref<'i>: 'o,
+{
+    #[inline(always)]
+    fn try_borrow_or_share(&'i self) -> Option<&'o T> {
+        match B::try_borrow_or_share(self) {
+            Some(r) => Some((r as B::Ref<'i>).cast()),
+            None => None,
+        }
+    }
+}
+
+/// A marker trait for types whose [`Bos<T>`] implementation always
+/// borrows from `*self`.
+///
+/// See the [crate-level documentation](crate#borrowing-versus-sharing) for more details.
+pub trait BorrowsFrom<T: ?Sized>: Bos<T> {}
+
+/// A marker trait for types whose [`Bos<T>`] implementation always
+/// shares a reference held by `*self`.
+///
+/// See the [crate-level documentation](crate#borrowing-versus-sharing) for more details.
+///
+/// # Examples
+///
+/// An owned type does not share:
+///
+/// ```compile_fail
+/// use borrow_or_share::SharesWith;
+///
+/// fn assert_shares<T: SharesWith<str>>(_: &T) {}
+///
+/// assert_shares(&String::from("hello"));
+/// ```
+pub trait SharesWith<T: ?Sized>: Bos<T> {}
+
+/// A trait for sharing data for `'o`, independently of the borrow of `self`.
+///
+/// A bound `B: Shares<'o, T>` accepts exactly the holders whose returned
+/// reference may outlive them, such as `&'o str`, and rejects owned types
+/// like `String`. This is useful for APIs that store the returned
+/// reference somewhere longer-lived than the holder.
+///
+/// Ideally this would be implemented on every `B` such that
+/// `for<'i> B: BorrowOrShare<'i, 'o, T>`, but such a bound also requires
+/// `B: 'i` for every `'i`, i.e., `B: 'static`. Instead, it is implemented
+/// along with every [`SharesWith`] implementation in this crate, and you
+/// may implement it on your own sharing holders.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::Shares;
+///
+/// fn register<'o, B: Shares<'o, str>>(registry: &mut Vec<&'o str>, b: B) {
+///     registry.push(b.share());
+/// }
+///
+/// let mut registry = Vec::new();
+/// let s = String::from("hello");
+/// register(&mut registry, s.as_str());
+/// register(&mut registry, &s);
+/// assert_eq!(registry, ["hello", "hello"]);
+/// ```
+///
+/// An owned type does not share:
+///
+/// ```compile_fail
+/// use borrow_or_share::Shares;
+///
+/// fn register<'o, B: Shares<'o, str>>(registry: &mut Vec<&'o str>, b: B) {
+///     registry.push(b.share());
+/// }
+///
+/// register(&mut Vec::new(), String::from("hello"));
+/// ```
+pub trait Shares<'o, T: ?Sized>: SharesWith<T> {
+    /// Shares the data held by `self` for `'o`.
+    fn share(&self) -> &'o T;
+}
+
+/// A marker trait for types whose [`Bos<T>`] implementation returns
+/// a reference whose address is stable when `self` is moved.
+///
+/// Implementing this trait asserts that moving the holder, without
+/// otherwise accessing it, neither invalidates nor relocates the data
+/// behind the returned reference, which is the property known elsewhere
+/// as `StableDeref`. This holds for holders that share data, and for those
+/// that keep their data on the heap. Self-referential and caching code
+/// may bound on it to refuse holders with inline storage.
+///
+/// This crate implements the trait on [`&'a T`](reference) and the
+/// projecting implementations on references, [`Vec<T>`], [`String`],
+/// [`CString`], [`OsString`], [`PathBuf`], [`Box<T>`], [`Rc<T>`], and
+/// [`Arc<T>`] (including `Rc<Vec<T>>` and the like). It is not implemented
+/// on [`[T; N]`](array) or [`&mut T`](reference) (including `&mut Vec<T>`
+/// and `&mut String`), nor on [`Cow<'_, B>`](Cow), whose owned form may
+/// store data inline. Forwarding implementations,
+/// such as the one on [`Cursor<T>`], implement it if the inner type does.
+///
+/// Note that this is a documented contract rather than something the
+/// compiler checks, because the trait is safe to implement.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::StableBos;
+///
+/// fn assert_stable<B: StableBos<[u8]>>(_: &B) {}
+///
+/// assert_stable(&vec![1, 2, 3]);
+/// assert_stable(&&[1, 2, 3]);
+/// ```
+///
+/// Arrays store their elements inline:
+///
+/// ```compile_fail
+/// use borrow_or_share::StableBos;
+///
+/// fn assert_stable<B: StableBos<[u8]>>(_: &B) {}
+///
+/// assert_stable(&[1u8, 2, 3]);
+/// ```
+///
+/// An exclusive reference cannot be shared, so the data is reborrowed
+/// through the holder:
+///
+/// ```compile_fail
+/// use borrow_or_share::StableBos;
+///
+/// fn assert_stable<B: StableBos<str>>(_: &B) {}
+///
+/// assert_stable(&String::from("hello").as_mut_str());
+/// ```
+///
+/// A [`Cow`] may be owned by a type with inline storage:
+///
+/// ```compile_fail
+/// use borrow_or_share::StableBos;
+/// use std::borrow::Cow;
+///
+/// fn assert_stable<B: StableBos<str>>(_: &B) {}
+///
+/// assert_stable(&Cow::Borrowed("hello"));
+/// ```
+pub trait StableBos<T: ?Sized>: Bos<T> {}
+
+/// A trait for constructing a value from a reference to data
+/// it can either borrow or share.
+///
+/// This is the construction side of [`Bos`]: an owned type clones the data,
+/// while [`&'a T`](reference) and [`Cow::Borrowed`] hold the reference as is.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, FromBorrowOrShare};
+///
+/// fn trimmed<'i, 'o, B>(b: &'i B) -> B
+/// where
+///     B: BorrowOrShare<'i, 'o, str> + FromBorrowOrShare<'o, str>,
+/// {
+///     B::from_ref(b.borrow_or_share().trim())
+/// }
+///
+/// assert_eq!(trimmed(&String::from(" owned ")), "owned");
+/// assert_eq!(trimmed(&" shared "), "shared");
+/// ```
+pub trait FromBorrowOrShare<'a, T: ?Sized> {
+    /// Constructs a value from a reference to data.
+    fn from_ref(r: &'a T) -> Self;
+}
+
+impl<'a, T: ?Sized> FromBorrowOrShare<'a, T> for &'a T {
+    #[inline]
+    fn from_ref(r: &'a T) -> Self {
+        r
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B: ?Sized + ToOwned> FromBorrowOrShare<'a, B> for Cow<'a, B> {
+    #[inline]
+    fn from_ref(r: &'a B) -> Self {
+        Cow::Borrowed(r)
+    }
+}
+
+macro_rules! impl_from_bos {
+    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty)*) => {
+        $(
+            $(#[$attr])?
+            impl<$($($params)*)?> FromBorrowOrShare<'_, $target> for $ty {
+                #[inline]
+                fn from_ref(r: &$target) -> Self {
+                    r.to_owned()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bos! {
+    #[cfg(feature = "alloc")]
+    {T: Clone} Vec<T> => [T]
+
+    #[cfg(feature = "alloc")]
+    String => str
+    #[cfg(feature = "alloc")]
+    CString => CStr
+
+    #[cfg(feature = "std")]
+    OsString => OsStr
+    #[cfg(feature = "std")]
+    PathBuf => Path
+}
+
+macro_rules! impl_from_bos_via_from {
+    ($($(#[$attr:meta])? $ptr:ident)*) => {
+        $(
+            $(#[$attr])?
+            impl<'a, T: ?Sized + 'a> FromBorrowOrShare<'a, T> for $ptr<T>
+            where
+                $ptr<T>: From<&'a T>,
+            {
+                #[inline]
+                fn from_ref(r: &'a T) -> Self {
+                    r.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bos_via_from! {
+    #[cfg(feature = "alloc")]
+    Box
+    #[cfg(feature = "alloc")]
+    Rc
+    #[cfg(feature = "alloc")]
+    Arc
+}
+
+/// Borrows or shares data of type `T` from `b`.
+///
+/// This is the same as [`BorrowOrShare::borrow_or_share`], but avoids
+/// the ambiguity of `B::borrow_or_share(b)`, which may refer to either
+/// [`Bos::borrow_or_share`] or [`BorrowOrShare::borrow_or_share`].
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::borrow_or_share;
+///
+/// fn share<'a>(s: &&'a String) -> &'a str {
+///     borrow_or_share(s)
+/// }
+///
+/// let s = String::from("hello");
+/// assert_eq!(borrow_or_share::<str, _>(&s), "hello");
+/// assert_eq!(share(&&s), "hello");
+/// ```
+#[inline]
+pub fn borrow_or_share<'i, 'o, T, B>(b: &'i B) -> &'o T
+where
+    T: ?Sized,
+    B: BorrowOrShare<'i, 'o, T> + ?Sized,
+{
+    b.borrow_or_share()
+}
+
+/// Borrows data of type `T` from `b`, without tying the result to `'o`.
+///
+/// This is a shorthand for `borrow_or_share::<T, B>(b)` where the returned
+/// reference only lives as long as the borrow of `b`, which is enough in
+/// most cases where the target type needs to be specified with turbofish
+/// syntax, e.g., when the holder implements [`Bos`] for multiple targets.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{bos_of, Bos};
+///
+/// struct Text(&'static str);
+///
+/// impl Bos<str> for Text {
+///     type Ref<'this> = &'static str;
+///
+///     fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+///         this.0
+///     }
+/// }
+///
+/// impl Bos<[u8]> for Text {
+///     type Ref<'this> = &'static [u8];
+///
+///     fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+///         this.0.as_bytes()
+///     }
+/// }
+///
+/// let text = Text("hello");
+/// assert_eq!(bos_of::<str, _>(&text), "hello");
+/// assert_eq!(bos_of::<[u8], _>(&text), b"hello");
+/// ```
+#[inline]
+pub fn bos_of<T, B>(b: &B) -> &T
+where
+    T: ?Sized,
+    B: Bos<T> + ?Sized,
+{
+    <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(b)
+}
+
+/// A zero-sized hint naming the target type `T` at a call site.
+///
+/// Turbofish syntax on [`borrow_or_share()`] requires naming the holder
+/// type too, if only as `_`, and is unavailable on methods whose generic
+/// parameters are not all given. Passing a `Target<T>` value instead pins
+/// down the target alone, which also suits macro-generated code. See
+/// [`borrow_or_share_with`] and [`BorrowOrShareExt::bos_with`].
+///
+/// # Examples
+///
+/// Without a hint, the target of a holder with multiple [`Bos`]
+/// implementations cannot be inferred:
+///
+/// ```compile_fail
+/// use borrow_or_share::borrow_or_share;
+///
+/// let s = String::from("hello");
+/// assert_eq!(borrow_or_share(&&s).len(), 5);
+/// ```
+///
+/// With a hint, it can:
+///
+/// ```
+/// use borrow_or_share::{borrow_or_share_with, Target};
+///
+/// let s = String::from("hello");
+/// assert_eq!(borrow_or_share_with(&&s, Target::<str>::new()).len(), 5);
+/// ```
+pub struct Target<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Target<T> {
+    /// Creates a hint for the target type `T`.
+    #[inline]
+    pub const fn new() -> Self {
+        Target(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Clone for Target<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Target<T> {}
+
+impl<T: ?Sized> Default for Target<T> {
+    #[inline]
+    fn default() -> Self {
+        Target::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Target<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Target<{}>", core::any::type_name::<T>())
+    }
+}
+
+/// Borrows or shares data of type `T` from `b`, with the target given
+/// by a [`Target<T>`] hint.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{borrow_or_share_with, Target};
+///
+/// fn share<'a>(s: &&'a String) -> &'a [u8] {
+///     borrow_or_share_with(s, Target::<str>::new()).as_bytes()
+/// }
+///
+/// let s = String::from("hello");
+/// assert_eq!(share(&&s), b"hello");
+/// ```
+#[inline]
+pub fn borrow_or_share_with<'i, 'o, T, B>(b: &'i B, _target: Target<T>) -> &'o T
+where
+    T: ?Sized,
+    B: BorrowOrShare<'i, 'o, T> + ?Sized,
+{
+    b.borrow_or_share()
+}
+
+/// Returns `true` if `a` and `b` borrow or share data at the same address.
+///
+/// Only the data pointers are compared, so any pointer metadata
+/// (e.g., the length of a slice) is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::borrow_or_share_ptr_eq;
+///
+/// let s = "hello";
+/// let (a, b) = (s, s);
+/// assert!(borrow_or_share_ptr_eq::<str, _, _>(&a, &b));
+///
+/// let (a, b) = (String::from(s), String::from(s));
+/// assert!(!borrow_or_share_ptr_eq::<str, _, _>(&a, &b));
+/// ```
+#[inline]
+pub fn borrow_or_share_ptr_eq<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: ?Sized,
+    A: Bos<T> + ?Sized,
+    B: Bos<T> + ?Sized,
+{
+    let a: *const T = <A as BorrowOrShare<'_, '_, T>>::borrow_or_share(a);
+    let b: *const T = <B as BorrowOrShare<'_, '_, T>>::borrow_or_share(b);
+    a.cast::<()>() == b.cast::<()>()
+}
+
+/// Concatenates the slices borrowed or shared from `a` and `b`
+/// into a new vector.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::borrow_or_share_concat;
+///
+/// let a = vec![1, 2];
+/// let b: &[u8] = &[3];
+/// assert_eq!(borrow_or_share_concat(&a, &b), [1, 2, 3]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn borrow_or_share_concat<T, A, B>(a: &A, b: &B) -> Vec<T>
+where
+    T: Clone,
+    A: Bos<[T]> + ?Sized,
+    B: Bos<[T]> + ?Sized,
+{
+    let a = bos_of::<[T], A>(a);
+    let b = bos_of::<[T], B>(b);
+    let mut vec = Vec::with_capacity(a.len() + b.len());
+    vec.extend_from_slice(a);
+    vec.extend_from_slice(b);
+    vec
+}
+
+/// Concatenates the string slices borrowed or shared from `a` and `b`
+/// into a new string.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::borrow_or_share_concat_str;
+///
+/// let a = String::from("hello, ");
+/// assert_eq!(borrow_or_share_concat_str(&a, &"world"), "hello, world");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn borrow_or_share_concat_str<A, B>(a: &A, b: &B) -> String
+where
+    A: Bos<str> + ?Sized,
+    B: Bos<str> + ?Sized,
+{
+    let a = bos_of::<str, A>(a);
+    let b = bos_of::<str, B>(b);
+    let mut string = String::with_capacity(a.len() + b.len());
+    string.push_str(a);
+    string.push_str(b);
+    string
+}
+
+/// Formats the string slice borrowed or shared from `this`.
+///
+/// This is meant to be called from [`Display`](fmt::Display) or
+/// [`Debug`](fmt::Debug) implementations of wrappers over [`Bos<str>`]
+/// holders. The string slice is formatted as with [`Display`](fmt::Display),
+/// so flags such as width and alignment are honored. See [`DisplayBos`]
+/// for a ready-made wrapper.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{borrow_or_share_fmt, Bos};
+/// use std::fmt;
+///
+/// struct Text<T>(T);
+///
+/// impl<T: Bos<str>> fmt::Display for Text<T> {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         borrow_or_share_fmt(&self.0, f)
+///     }
+/// }
+///
+/// assert_eq!(Text(String::from("hello")).to_string(), "hello");
+/// assert_eq!(format!("[{:<6}]", Text("hello")), "[hello ]");
+/// ```
+#[inline]
+pub fn borrow_or_share_fmt<B>(this: &B, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    B: Bos<str> + ?Sized,
+{
+    fmt::Display::fmt(bos_of::<str, B>(this), f)
+}
+
+/// Hashes the data borrowed or shared from `this`.
+///
+/// This is meant to be called from [`Hash`] implementations of wrappers
+/// over [`Bos`] holders, so that wrappers over equal data hash equally
+/// regardless of how the data is held, e.g. in a `String` or a `&str`.
+/// The target type usually needs to be specified with turbofish syntax.
+/// See [`ByTarget`] for a ready-made wrapper, which also implements the
+/// comparison traits consistently.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{borrow_or_share_hash, Bos};
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// struct Text<T>(T);
+///
+/// impl<T: Bos<str>> Hash for Text<T> {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         borrow_or_share_hash::<str, _, _>(&self.0, state)
+///     }
+/// }
+///
+/// fn hash_of(t: &impl Hash) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     t.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// assert_eq!(hash_of(&Text(String::from("hello"))), hash_of(&Text("hello")));
+/// ```
+#[inline]
+pub fn borrow_or_share_hash<T, B, H>(this: &B, state: &mut H)
+where
+    T: Hash + ?Sized,
+    B: Bos<T> + ?Sized,
+    H: Hasher,
+{
+    bos_of::<T, B>(this).hash(state)
+}
+
+macro_rules! const_impls {
+    ($($c:tt)?) => {
+        impl<'a, T: ?Sized> $($c)? Bos<T> for &'a T {
+            type Ref<'this> = &'a T where Self: 'this;
+
+            #[inline]
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                this
+            }
+        }
+
+        impl<T: ?Sized> $($c)? Bos<T> for &mut T {
+            type Ref<'this> = &'this T where Self: 'this;
+
+            #[inline]
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                this
+            }
+        }
+
+        impl<T, const N: usize> $($c)? Bos<[T]> for [T; N] {
+            type Ref<'this> = &'this [T] where Self: 'this;
+
+            #[inline]
+            fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                this
+            }
+        }
+    };
+}
+
+with_const!(const_impls);
+
+impl<T: ?Sized> BorrowsFrom<T> for &mut T {}
+impl<T, const N: usize> BorrowsFrom<[T]> for [T; N] {}
+
+impl<T: ?Sized> SharesWith<T> for &T {}
+impl<T: ?Sized> StableBos<T> for &T {}
+
+impl<'a, T: ?Sized> Shares<'a, T> for &'a T {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self
+    }
+}
+
+impl<T> Bos<[Cell<T>]> for Cell<[T]> {
+    type Ref<'this> = &'this [Cell<T>] where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_slice_of_cells()
+    }
+}
+
+impl<T, const N: usize> Bos<[Cell<T>]> for Cell<[T; N]> {
+    type Ref<'this> = &'this [Cell<T>] where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        let this: &Cell<[T]> = this;
+        this.as_slice_of_cells()
+    }
+}
+
+impl<T> BorrowsFrom<[Cell<T>]> for Cell<[T]> {}
+impl<T, const N: usize> BorrowsFrom<[Cell<T>]> for Cell<[T; N]> {}
+
+/// Borrows the contents of the deque as a single slice.
+///
+/// # Panics
+///
+/// Panics if the deque is not contiguous, i.e., if the second slice
+/// returned by [`VecDeque::as_slices`] is non-empty. Call
+/// [`VecDeque::make_contiguous`] beforehand, use the [`TryBos`]
+/// implementation instead, or wrap the deque in a [`Contiguous`],
+/// to avoid this.
+#[cfg(feature = "alloc")]
+impl<T> Bos<[T]> for VecDeque<T> {
+    type Ref<'this> = &'this [T] where Self: 'this;
+
+    #[inline]
+    #[track_caller]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        let (front, back) = this.as_slices();
+        assert!(back.is_empty(), "`VecDeque` is not contiguous");
+        front
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> BorrowsFrom<[T]> for VecDeque<T> {}
+
+/// Borrows the contents of the deque as a single slice,
+/// returning `None` if the deque is not contiguous.
+#[cfg(feature = "alloc")]
+impl<T> TryBos<[T]> for VecDeque<T> {
+    type Ref<'this> = &'this [T] where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        match this.as_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+}
+
+/// Borrows the elements of the array as a slice.
+///
+/// # Panics
+///
+/// Panics if the array is not contiguous in standard order, i.e., if
+/// [`Array1::as_slice`](ndarray::ArrayBase::as_slice) returns `None`,
+/// which happens after slicing it in place with a step or inverting
+/// its axis. Use the [`TryBos`] implementation instead to avoid this.
+#[cfg(feature = "ndarray")]
+impl<T> Bos<[T]> for ndarray::Array1<T> {
+    type Ref<'this> = &'this [T] where Self: 'this;
+
+    #[inline]
+    #[track_caller]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.as_slice().expect("`Array1` is not contiguous")
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> BorrowsFrom<[T]> for ndarray::Array1<T> {}
+
+/// Borrows the elements of the array as a slice,
+/// returning `None` if the array is not contiguous.
+#[cfg(feature = "ndarray")]
+impl<T> TryBos<[T]> for ndarray::Array1<T> {
+    type Ref<'this> = &'this [T] where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        this.as_slice()
+    }
+}
+
+/// Shares the elements of the view as a slice for `'a`.
+///
+/// # Panics
+///
+/// Panics if the view is not contiguous in standard order, e.g., if it
+/// was sliced with a step. Use the [`TryBos`] implementation instead
+/// to avoid this.
+#[cfg(feature = "ndarray")]
+impl<'a, T> Bos<[T]> for ndarray::ArrayView1<'a, T> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    #[track_caller]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.to_slice().expect("`ArrayView1` is not contiguous")
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> SharesWith<[T]> for ndarray::ArrayView1<'_, T> {}
+
+/// Shares the elements of the view as a slice for `'a`.
+///
+/// # Panics
+///
+/// Panics if the view is not contiguous in standard order, like the
+/// [`Bos`] implementation.
+#[cfg(feature = "ndarray")]
+impl<'a, T> Shares<'a, [T]> for ndarray::ArrayView1<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn share(&self) -> &'a [T] {
+        self.to_slice().expect("`ArrayView1` is not contiguous")
+    }
+}
+
+/// Shares the elements of the view as a slice for `'a`,
+/// returning `None` if the view is not contiguous.
+#[cfg(feature = "ndarray")]
+impl<'a, T> TryBos<[T]> for ndarray::ArrayView1<'a, T> {
+    type Ref<'this> = &'a [T] where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        this.to_slice()
+    }
+}
+
+/// Forwards to the inner buffer, preserving its sharing behavior.
+#[cfg(feature = "std")]
+impl<T: Bos<[u8]>> Bos<[u8]> for Cursor<T> {
+    type Ref<'this> = T::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        T::borrow_or_share(this.get_ref())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: BorrowsFrom<[u8]>> BorrowsFrom<[u8]> for Cursor<T> {}
+#[cfg(feature = "std")]
+impl<T: SharesWith<[u8]>> SharesWith<[u8]> for Cursor<T> {}
+#[cfg(feature = "std")]
+impl<T: StableBos<[u8]>> StableBos<[u8]> for Cursor<T> {}
+#[cfg(feature = "std")]
+impl<'o, T: Shares<'o, [u8]>> Shares<'o, [u8]> for Cursor<T> {
+    #[inline]
+    fn share(&self) -> &'o [u8] {
+        self.get_ref().share()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: TryBos<[u8]>> TryBos<[u8]> for Cursor<T> {
+    type Ref<'this> = T::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        T::try_borrow_or_share(this.get_ref())
+    }
+}
+
+/// Forwards to the inner holder, preserving its sharing behavior.
+///
+/// There is no implementation of `Bos<B>` on `ManuallyDrop<B>`, as it would
+/// overlap with this one. Dereference the `ManuallyDrop<B>` to get a `&B`.
+impl<T: ?Sized, B: Bos<T>> Bos<T> for ManuallyDrop<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(this)
+    }
+}
+
+impl<T: ?Sized, B: BorrowsFrom<T>> BorrowsFrom<T> for ManuallyDrop<B> {}
+impl<T: ?Sized, B: SharesWith<T>> SharesWith<T> for ManuallyDrop<B> {}
+impl<T: ?Sized, B: StableBos<T>> StableBos<T> for ManuallyDrop<B> {}
+
+impl<'o, T: ?Sized, B: Shares<'o, T>> Shares<'o, T> for ManuallyDrop<B> {
+    #[inline]
+    fn share(&self) -> &'o T {
+        B::share(self)
+    }
+}
+
+impl<T: ?Sized, B: TryBos<T>> TryBos<T> for ManuallyDrop<B> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(this)
+    }
+}
+
+/// Shares the pinned data for `'a`, like `&'a T`.
+impl<'a, T: ?Sized> Bos<T> for Pin<&'a T> {
+    type Ref<'this> = &'a T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        Pin::get_ref(*this)
+    }
+}
+
+impl<T: ?Sized> SharesWith<T> for Pin<&T> {}
+impl<T: ?Sized> StableBos<T> for Pin<&T> {}
+
+impl<'a, T: ?Sized> Shares<'a, T> for Pin<&'a T> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        Pin::get_ref(*self)
+    }
+}
+
+/// Borrows the pinned data from `*self`, like `&mut T`.
+///
+/// An exclusive reference cannot be copied out from behind `&'this Self`,
+/// so the data cannot be shared for `'a`. [`BosMut`] is only implemented
+/// if `T: Unpin`, because handing out `&mut T` to pinned data would allow
+/// moving it out, e.g., with [`mem::swap`](core::mem::swap).
+///
+/// # Examples
+///
+/// ```compile_fail
+/// use borrow_or_share::BorrowOrShare;
+/// use std::pin::Pin;
+///
+/// fn share<'a>(pin: &Pin<&'a mut str>) -> &'a str {
+///     pin.borrow_or_share()
+/// }
+/// ```
+impl<T: ?Sized> Bos<T> for Pin<&mut T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this
+    }
+}
+
+impl<T: ?Sized> BorrowsFrom<T> for Pin<&mut T> {}
+
+impl<T: ?Sized + Unpin> BosMut<T> for Pin<&mut T> {
+    type RefMut<'this> = &'this mut T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        this
+    }
+}
+
+/// Implemented for any `T: 'static` so that uninhabited holders can
+/// appear in generic code, e.g., as a variant of an enum.
+impl<T: ?Sized + 'static> Bos<T> for Infallible {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        match *this {}
+    }
+}
+
+impl<T: ?Sized + 'static> BorrowsFrom<T> for Infallible {}
+
+impl<T: ?Sized + 'static> BosMut<T> for Infallible {
+    type RefMut<'this> = &'this mut T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        match *this {}
+    }
+}
+
+/// Delegates to whichever variant is present.
+///
+/// Since the variant is only known at runtime, the implementation cannot
+/// share data even if both `A` and `E` do, and always borrows from `*self`.
+/// The target must be `'static`, because `T` does not appear in `Self`
+/// and thus cannot be proven to outlive the borrow otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::BorrowOrShare;
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// // Either a normalized copy or the original slice.
+/// fn normalize(s: &str) -> Result<String, &str> {
+///     if s.contains(char::is_uppercase) {
+///         Ok(s.to_lowercase())
+///     } else {
+///         Err(s)
+///     }
+/// }
+///
+/// let orig = String::from("hello");
+/// let res = normalize(&orig);
+/// let s = as_str(&res);
+/// // `s` borrows from `res` rather than `orig`, even though `res` is `Err`.
+/// assert_eq!(s, "hello");
+/// ```
+impl<T: ?Sized + 'static, A: Bos<T>, E: Bos<T>> Bos<T> for Result<A, E> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        match this {
+            Ok(a) => <A as BorrowOrShare<'_, '_, T>>::borrow_or_share(a),
+            Err(e) => <E as BorrowOrShare<'_, '_, T>>::borrow_or_share(e),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static, A: Bos<T>, E: Bos<T>> BorrowsFrom<T> for Result<A, E> {}
+impl<T: ?Sized + 'static, A: StableBos<T>, E: StableBos<T>> StableBos<T> for Result<A, E> {}
+
+macro_rules! impl_try_bos_via_bos {
+    ($($(#[$attr:meta])* $({$($params:tt)*})? $ty:ty => $target:ty)*) => {
+        $(
+            $(#[$attr])*
+            impl $(<$($params)*>)? TryBos<$target> for $ty {
+                type Ref<'this> = <Self as Bos<$target>>::Ref<'this> where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+                    Some(<Self as Bos<$target>>::borrow_or_share(this))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_bos_via_bos! {
+    {'a, T: ?Sized} &'a T => T
+    {T: ?Sized} &mut T => T
+    {T, const N: usize} [T; N] => [T]
+    {T} Cell<[T]> => [Cell<T>]
+    {T, const N: usize} Cell<[T; N]> => [Cell<T>]
+    {T: ?Sized + 'static} Infallible => T
+    {T: ?Sized + 'static, A: Bos<T>, E: Bos<T>} Result<A, E> => T
+    {'a, T: ?Sized} Pin<&'a T> => T
+    {T: ?Sized} Pin<&mut T> => T
+}
+
+macro_rules! impl_try_bos_for_option_like {
+    ($($(#[$attr:meta])* $holder:ident => $get:expr)*) => {
+        $(
+            $(#[$attr])*
+            impl<T: ?Sized, B: Bos<T>> TryBos<T> for $holder<B> {
+                type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+                    let get: fn(&Self) -> Option<&B> = $get;
+                    get(this).map(B::borrow_or_share)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_bos_for_option_like! {
+    Option => Option::as_ref
+    #[cfg(bos_once_cell)]
+    #[allow(clippy::incompatible_msrv)]
+    OnceCell => OnceCell::get
+    #[cfg(all(feature = "std", bos_once_cell))]
+    #[allow(clippy::incompatible_msrv)]
+    OnceLock => OnceLock::get
+}
+
+macro_rules! impl_bos {
+    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty $(, via $via:ident)?)*) => {
+        $(
+            $(#[$attr])?
+            impl $(<$($params)*>)? Bos<$target> for $ty {
+                type Ref<'this> = &'this $target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                    this $(.$via())?
+                }
+            }
+
+            $(#[$attr])?
+            impl $(<$($params)*>)? BorrowsFrom<$target> for $ty {}
+
+            impl_try_bos_via_bos! {
+                $(#[$attr])? $({$($params)*})? $ty => $target
+            }
+        )*
+    };
+}
+
+impl_bos! {
+    // A blanket impl would show up everywhere in the
+    // documentation of a dependent crate, which is noisy.
+    // So we're omitting it for the moment.
+    // {T: ?Sized} T => T
+
+    {T, const N: usize} &mut [T; N] => [T], via as_slice
+    #[cfg(feature = "alloc")]
+    {T} &mut Vec<T> => [T], via as_slice
+    #[cfg(feature = "alloc")]
+    &mut String => str, via as_str
+
+    #[cfg(feature = "alloc")]
+    {T} Vec<T> => [T]
+
+    #[cfg(feature = "alloc")]
+    String => str
+    #[cfg(feature = "alloc")]
+    CString => CStr
+
+    #[cfg(feature = "std")]
+    OsString => OsStr
+    #[cfg(feature = "std")]
+    PathBuf => Path
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Box<T> => T
+    #[cfg(feature = "alloc")]
+    {B: ?Sized + ToOwned} Cow<'_, B> => B
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Rc<T> => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Arc<T> => T
+
+    #[cfg(feature = "alloc")]
+    {T} Rc<Vec<T>> => [T]
+    #[cfg(feature = "alloc")]
+    {T} Arc<Vec<T>> => [T]
+    #[cfg(feature = "alloc")]
+    Rc<String> => str
+    #[cfg(feature = "alloc")]
+    Arc<String> => str
+    #[cfg(feature = "alloc")]
+    Rc<str> => [u8], via as_bytes
+    #[cfg(feature = "alloc")]
+    Arc<str> => [u8], via as_bytes
+
+    #[cfg(feature = "glib")]
+    glib::GString => str
+    #[cfg(feature = "glib")]
+    glib::GString => glib::GStr, via as_gstr
+    #[cfg(feature = "glib")]
+    glib::GStr => str
+
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => camino::Utf8Path, via as_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => Path, via as_std_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => OsStr, via as_os_str
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8Path => Path, via as_std_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8Path => OsStr, via as_os_str
+
+    #[cfg(feature = "zerovec")]
+    zerovec::ZeroVec<'_, u8> => [u8], via as_bytes
+    #[cfg(feature = "zerovec")]
+    zerovec::ZeroSlice<u8> => [u8], via as_bytes
+}
+
+macro_rules! impl_stable_bos {
+    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty)*) => {
+        $(
+            $(#[$attr])?
+            impl $(<$($params)*>)? StableBos<$target> for $ty {}
+        )*
+    };
+}
+
+// `Cow` and `GString` are omitted, as they may store data inline.
+impl_stable_bos! {
+    #[cfg(feature = "alloc")]
+    {T} Vec<T> => [T]
+
+    #[cfg(feature = "alloc")]
+    String => str
+    #[cfg(feature = "alloc")]
+    CString => CStr
+
+    #[cfg(feature = "std")]
+    OsString => OsStr
+    #[cfg(feature = "std")]
+    PathBuf => Path
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Box<T> => T
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Rc<T> => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Arc<T> => T
+
+    #[cfg(feature = "alloc")]
+    {T} Rc<Vec<T>> => [T]
+    #[cfg(feature = "alloc")]
+    {T} Arc<Vec<T>> => [T]
+    #[cfg(feature = "alloc")]
+    Rc<String> => str
+    #[cfg(feature = "alloc")]
+    Arc<String> => str
+    #[cfg(feature = "alloc")]
+    Rc<str> => [u8]
+    #[cfg(feature = "alloc")]
+    Arc<str> => [u8]
+
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => camino::Utf8Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => OsStr
+
+    #[cfg(feature = "zerovec")]
+    zerovec::ZeroVec<'_, u8> => [u8]
+}
+
+macro_rules! impl_bos_shared {
+    ($($(#[$attr:meta])* $({$($params:tt)*})? &$a:lifetime $ty:ty => $target:ty $(, via $via:ident)?)*) => {
+        $(
+            $(#[$attr])*
+            impl<$a, $($($params)*)?> Bos<$target> for &$a $ty {
+                type Ref<'this> = &$a $target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                    (*this) $(.$via())?
+                }
+            }
+
+            $(#[$attr])*
+            impl<$a, $($($params)*)?> SharesWith<$target> for &$a $ty {}
+
+            $(#[$attr])*
+            impl<$a, $($($params)*)?> StableBos<$target> for &$a $ty {}
+
+            $(#[$attr])*
+            impl<$a, $($($params)*)?> Shares<$a, $target> for &$a $ty {
+                #[inline]
+                fn share(&self) -> &$a $target {
+                    (*self) $(.$via())?
+                }
+            }
+
+            impl_try_bos_via_bos! {
+                $(#[$attr])* {$a, $($($params)*)?} &$a $ty => $target
+            }
+        )*
+    };
+}
+
+impl_bos_shared! {
+    {T, const N: usize} &'a [T; N] => [T]
+
+    #[cfg(feature = "alloc")]
+    {T} &'a Vec<T> => [T]
+
+    #[cfg(feature = "alloc")]
+    &'a String => str
+    #[cfg(feature = "alloc")]
+    {T} &'a Box<[T]> => [T]
+    #[cfg(feature = "alloc")]
+    &'a Box<str> => str
+    #[cfg(feature = "alloc")]
+    {B: ?Sized + ToOwned} &'a Cow<'_, B> => B
+
+    #[cfg(feature = "std")]
+    &'a OsString => OsStr
+
+    #[cfg(feature = "glib")]
+    &'a glib::GStr => str
+
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &'a camino::Utf8PathBuf => camino::Utf8Path, via as_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &'a camino::Utf8PathBuf => Path, via as_std_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &'a camino::Utf8PathBuf => OsStr, via as_os_str
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &'a camino::Utf8Path => Path, via as_std_path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &'a camino::Utf8Path => OsStr, via as_os_str
+
+    #[cfg(feature = "zerovec")]
+    &'a zerovec::ZeroVec<'_, u8> => [u8], via as_bytes
+    #[cfg(feature = "zerovec")]
+    &'a zerovec::ZeroSlice<u8> => [u8], via as_bytes
+
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {T, F: FnOnce() -> T} &'a LazyLock<T, F> => T
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {T, F: FnOnce() -> Vec<T>} &'a LazyLock<Vec<T>, F> => [T]
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {F: FnOnce() -> String} &'a LazyLock<String, F> => str
+}
+
+/// Shares the bytes as a string slice if they are valid UTF-8,
+/// returning `None` otherwise.
+impl<'a, const N: usize> TryBos<str> for &'a [u8; N] {
+    type Ref<'this> = &'a str where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        core::str::from_utf8(*this).ok()
+    }
+}
+
+macro_rules! impl_try_bos_str_for_os_str {
+    ($($({$a:lifetime})? $ty:ty => $lt:lifetime)*) => {
+        $(
+            #[cfg(feature = "std")]
+            impl$(<$a>)? TryBos<str> for $ty {
+                type Ref<'this> = &$lt str where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+                    this.to_str()
+                }
+            }
+        )*
+    };
+}
+
+// Borrows or shares the string as a string slice if it is valid UTF-8,
+// returning `None` otherwise.
+impl_try_bos_str_for_os_str! {
+    OsStr => 'this
+    OsString => 'this
+    {'a} &'a OsStr => 'a
+    {'a} &'a OsString => 'a
+}
+
+/// Shares the string slice returned by [`AsRef::as_ref`].
+///
+/// Since `as_ref` is called on the held `&'a dyn AsRef<str>` itself,
+/// the returned reference lives for `'a` rather than `'this`.
+impl<'a> Bos<str> for &'a (dyn AsRef<str> + 'a) {
+    type Ref<'this> = &'a str where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        (*this).as_ref()
+    }
+}
+
+impl<'a> SharesWith<str> for &'a (dyn AsRef<str> + 'a) {}
+impl<'a> StableBos<str> for &'a (dyn AsRef<str> + 'a) {}
+
+impl<'a> Shares<'a, str> for &'a (dyn AsRef<str> + 'a) {
+    #[inline]
+    fn share(&self) -> &'a str {
+        (*self).as_ref()
+    }
+}
+
+impl_try_bos_via_bos! {
+    {'a} &'a (dyn AsRef<str> + 'a) => str
+}
+
+macro_rules! impl_bos_mut {
+    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty $(, via $via:ident)?)*) => {
+        $(
+            $(#[$attr])?
+            impl $(<$($params)*>)? BosMut<$target> for $ty {
+                type RefMut<'this> = &'this mut $target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+                    this $(.$via())?
+                }
+            }
+        )*
+    };
+}
+
+impl_bos_mut! {
+    {T: ?Sized} &mut T => T
+    {T, const N: usize} &mut [T; N] => [T], via as_mut_slice
+    #[cfg(feature = "alloc")]
+    {T} &mut Vec<T> => [T], via as_mut_slice
+    #[cfg(feature = "alloc")]
+    &mut String => str, via as_mut_str
+
+    {T, const N: usize} [T; N] => [T]
+    #[cfg(feature = "alloc")]
+    {T} Vec<T> => [T]
+
+    #[cfg(feature = "alloc")]
+    String => str
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} Box<T> => T
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + ToOwned> BosMut<B> for Cow<'_, B>
+where
+    B::Owned: BorrowMut<B>,
+{
+    type RefMut<'this> = &'this mut B where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        this.to_mut().borrow_mut()
+    }
+}
+
+/// A trait for converting borrowed or shared data into owned data.
+///
+/// [`to_owned_target`] clones the data behind a holder, while
+/// [`into_owned_target`] consumes the holder and reuses its allocation
+/// where possible. For example, a `String` is moved out as is, and a
+/// [`Cow::Owned`] is unwrapped, while a `&str` is copied into a new `String`.
+///
+/// [`into_owned_target`] is the by-value counterpart of [`Bos`], for APIs
+/// that take ownership of a holder, such as a generic builder finalizing
+/// into an owned value: bound on `B: BosToOwned<T>` rather than introducing
+/// a separate trait for consuming holders.
+///
+/// Both methods are provided, with [`into_owned_target`] cloning by default.
+/// This trait is implemented on all holders in this crate, with wrappers
+/// such as [`BosCell`] and [`ByTarget`] forwarding [`into_owned_target`]
+/// to the holder they own when it has the same target. For your own
+/// holder, write an empty implementation, overriding [`into_owned_target`]
+/// if the holder owns data in the form of `T::Owned`.
+///
+/// [`to_owned_target`]: Self::to_owned_target
+/// [`into_owned_target`]: Self::into_owned_target
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::BosToOwned;
+///
+/// fn into_string<B: BosToOwned<str>>(b: B) -> String {
+///     b.into_owned_target()
+/// }
+///
+/// let s = String::from("owned");
+/// let ptr = s.as_ptr();
+/// let owned = into_string(s);
+/// assert_eq!(owned.as_ptr(), ptr);
+///
+/// assert_eq!(into_string("shared"), "shared");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait BosToOwned<T: ?Sized + ToOwned>: Bos<T> {
+    /// Clones the data behind `self` into owned data.
+    #[inline]
+    fn to_owned_target(&self) -> T::Owned {
+        <Self as BorrowOrShare<'_, '_, T>>::borrow_or_share(self).to_owned()
+    }
+
+    /// Converts `self` into owned data, reusing its allocation where possible.
+    #[inline]
+    fn into_owned_target(self) -> T::Owned
+    where
+        Self: Sized,
+    {
+        self.to_owned_target()
+    }
+}
+
+/// A helper trait for wrapping borrowed or shared data in a [`Cow`].
+///
+/// This is implemented on all types that implement [`BorrowOrShare`],
+/// and always returns [`Cow::Borrowed`], preserving the lifetime `'o`.
+/// It gives public APIs a uniform way to return data that callers
+/// might want to own later.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, ToCow};
+/// use std::borrow::Cow;
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn to_cow(&'i self) -> Cow<'o, str> {
+///         self.0.to_cow()
+///     }
+/// }
+///
+/// fn share<'a>(text: &Text<&'a str>) -> Cow<'a, str> {
+///     text.to_cow()
+/// }
+///
+/// assert_eq!(Text(String::from("owned")).to_cow(), "owned");
+/// assert_eq!(share(&Text("shared")), "shared");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ToCow<'i, 'o, T: ?Sized + ToOwned>: BorrowOrShare<'i, 'o, T> {
+    /// Borrows or shares the data, wrapping it in [`Cow::Borrowed`].
+    fn to_cow(&'i self) -> Cow<'o, T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<'i, 'o, T: ?Sized + ToOwned + 'o, B> ToCow<'i, 'o, T> for B
+where
+    B: BorrowOrShare<'i, 'o, T> + ?Sized,
+{
+    #[inline]
+    fn to_cow(&'i self) -> Cow<'o, T> {
+        Cow::Borrowed(self.borrow_or_share())
+    }
+}
+
+macro_rules! impl_bos_to_owned {
+    ($($(#[$attr:meta])* $({$($params:tt)*})? $ty:ty => $target:ty $(, |$this:ident| $into:expr)?)*) => {
+        $(
+            $(#[$attr])*
+            impl $(<$($params)*>)? BosToOwned<$target> for $ty {
+                $(
+                    #[inline]
+                    fn into_owned_target(self) -> <$target as ToOwned>::Owned {
+                        let $this = self;
+                        $into
+                    }
+                )?
+            }
+        )*
+    };
+}
+
+impl_bos_to_owned! {
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} &T => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} &mut T => T
+    #[cfg(feature = "alloc")]
+    {T: Clone, const N: usize} [T; N] => [T], |this| this.into()
+    #[cfg(feature = "alloc")]
+    {T: Clone, const N: usize} &mut [T; N] => [T]
+    #[cfg(feature = "alloc")]
+    {T: Clone} &mut Vec<T> => [T]
+    #[cfg(feature = "alloc")]
+    &mut String => str
+
+    #[cfg(feature = "alloc")]
+    {T: Copy} Cell<[T]> => [Cell<T>]
+    #[cfg(feature = "alloc")]
+    {T: Copy, const N: usize} Cell<[T; N]> => [Cell<T>]
+
+    #[cfg(feature = "alloc")]
+    {T: Clone} Vec<T> => [T], |this| this
+
+    #[cfg(feature = "alloc")]
+    String => str, |this| this
+    #[cfg(feature = "alloc")]
+    CString => CStr, |this| this
+
+    #[cfg(feature = "std")]
+    OsString => OsStr, |this| this
+    #[cfg(feature = "std")]
+    PathBuf => Path, |this| this
+
+    #[cfg(feature = "alloc")]
+    {B: ?Sized + ToOwned} Cow<'_, B> => B, |this| this.into_owned()
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} Rc<T> => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} Arc<T> => T
+
+    #[cfg(feature = "alloc")]
+    {T: Clone} Rc<Vec<T>> => [T], |this| Rc::try_unwrap(this).unwrap_or_else(|rc| (*rc).clone())
+    #[cfg(feature = "alloc")]
+    {T: Clone} Arc<Vec<T>> => [T], |this| Arc::try_unwrap(this).unwrap_or_else(|arc| (*arc).clone())
+    #[cfg(feature = "alloc")]
+    Rc<String> => str, |this| Rc::try_unwrap(this).unwrap_or_else(|rc| (*rc).clone())
+    #[cfg(feature = "alloc")]
+    Arc<String> => str, |this| Arc::try_unwrap(this).unwrap_or_else(|arc| (*arc).clone())
+    #[cfg(feature = "alloc")]
+    Rc<str> => [u8]
+    #[cfg(feature = "alloc")]
+    Arc<str> => [u8]
+
+    #[cfg(feature = "alloc")]
+    {T: Clone, const N: usize} &[T; N] => [T]
+    #[cfg(feature = "alloc")]
+    {T: Clone} &Vec<T> => [T]
+    #[cfg(feature = "alloc")]
+    &String => str
+    #[cfg(feature = "alloc")]
+    {T: Clone} &Box<[T]> => [T]
+    #[cfg(feature = "alloc")]
+    &Box<str> => str
+    #[cfg(feature = "alloc")]
+    {B: ?Sized + ToOwned} &Cow<'_, B> => B
+    #[cfg(feature = "std")]
+    &OsString => OsStr
+    #[cfg(feature = "alloc")]
+    &dyn AsRef<str> => str
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {T: Clone, F: FnOnce() -> T} &LazyLock<T, F> => T
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {T: Clone, F: FnOnce() -> Vec<T>} &LazyLock<Vec<T>, F> => [T]
+    #[cfg(all(feature = "std", bos_lazy_lock))]
+    #[allow(clippy::incompatible_msrv)]
+    {F: FnOnce() -> String} &LazyLock<String, F> => str
+
+    #[cfg(feature = "std")]
+    {T: BosToOwned<[u8]>} Cursor<T> => [u8], |this| this.into_inner().into_owned_target()
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned, B: BosToOwned<T>} ManuallyDrop<B> => T,
+        |this| ManuallyDrop::into_inner(this).into_owned_target()
+
+    #[cfg(feature = "alloc")]
+    LazyStr<'_> => str, |this| this.into_owned()
+    #[cfg(feature = "std")]
+    Segments => [u16; 8]
+    #[cfg(feature = "std")]
+    Segments => [u16], |this| this.get().into()
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} Pin<&T> => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned} Pin<&mut T> => T
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned + 'static} Infallible => T, |this| match this {}
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + ToOwned + 'static, A: BosToOwned<T>, E: BosToOwned<T>} Result<A, E> => T, |this| match this {
+        Ok(a) => a.into_owned_target(),
+        Err(e) => e.into_owned_target(),
+    }
+
+    #[cfg(all(feature = "alloc", feature = "glib"))]
+    glib::GString => str
+    #[cfg(all(feature = "alloc", feature = "glib"))]
+    glib::GString => glib::GStr, |this| this
+    #[cfg(all(feature = "alloc", feature = "glib"))]
+    glib::GStr => str
+    #[cfg(all(feature = "alloc", feature = "glib"))]
+    &glib::GStr => str
+
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => camino::Utf8Path, |this| this
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => Path, |this| this.into_std_path_buf()
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8PathBuf => OsStr, |this| this.into_os_string()
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8Path => Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    camino::Utf8Path => OsStr
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &camino::Utf8PathBuf => camino::Utf8Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &camino::Utf8PathBuf => Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &camino::Utf8PathBuf => OsStr
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &camino::Utf8Path => Path
+    #[cfg(all(feature = "std", feature = "camino"))]
+    &camino::Utf8Path => OsStr
+
+    #[cfg(all(feature = "alloc", feature = "zerovec"))]
+    zerovec::ZeroVec<'_, u8> => [u8]
+    #[cfg(all(feature = "alloc", feature = "zerovec"))]
+    zerovec::ZeroSlice<u8> => [u8]
+    #[cfg(all(feature = "alloc", feature = "zerovec"))]
+    &zerovec::ZeroVec<'_, u8> => [u8]
+    #[cfg(all(feature = "alloc", feature = "zerovec"))]
+    &zerovec::ZeroSlice<u8> => [u8]
+}
+
+// The data may not be contiguous, in which case `Bos::borrow_or_share`
+// panics, but an owned copy can always be made by iterating.
+#[cfg(feature = "alloc")]
+impl<T: Clone> BosToOwned<[T]> for VecDeque<T> {
+    #[inline]
+    fn to_owned_target(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    #[inline]
+    fn into_owned_target(self) -> Vec<T> {
+        self.into()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "ndarray"))]
+impl<T: Clone> BosToOwned<[T]> for ndarray::Array1<T> {
+    #[inline]
+    fn to_owned_target(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    #[inline]
+    fn into_owned_target(self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "ndarray"))]
+impl<T: Clone> BosToOwned<[T]> for ndarray::ArrayView1<'_, T> {
+    #[inline]
+    fn to_owned_target(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    #[inline]
+    fn into_owned_target(self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+/// Moves the data out of the box if `T::Owned` can be converted from it,
+/// which is the case for `str`, slices, and the other unsized types
+/// in the standard library.
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + ToOwned> BosToOwned<T> for Box<T>
+where
+    Box<T>: Into<T::Owned>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: core::ops::Deref> BosToOwned<B::Target> for ViaDeref<B>
+where
+    B::Target: ToOwned,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + ToOwned + 'static, B: core::borrow::Borrow<T>> BosToOwned<T> for ViaBorrow<B> {}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + ToOwned + 'static, B: AsRef<T>> BosToOwned<T> for ViaAsRef<B> {}
+
+/// A trait for turning borrowed or shared data into `'static` data,
+/// leaking it if necessary.
+///
+/// Shared data that already lives for `'static`, such as a `&'static str`,
+/// is passed through unchanged, while owned data is leaked with
+/// [`Box::leak`]. The leaked memory is never freed, so use this only
+/// for data that is meant to live until the end of the program.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::BorrowOrShareLeak;
+///
+/// fn intern<B: BorrowOrShareLeak<str>>(b: B) -> &'static str {
+///     b.borrow_or_share_leak()
+/// }
+///
+/// let s: &'static str = "shared";
+/// assert!(std::ptr::eq(intern(s), s));
+///
+/// assert_eq!(intern(String::from("owned")), "owned");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait BorrowOrShareLeak<T: ?Sized + 'static>: Bos<T> {
+    /// Returns the data as `&'static T`, leaking it if it is owned.
+    fn borrow_or_share_leak(self) -> &'static T;
+}
+
+macro_rules! impl_bos_leak {
+    ($($(#[$attr:meta])? $({$($params:tt)*})? $ty:ty => $target:ty, |$this:ident| $leak:expr)*) => {
+        $(
+            $(#[$attr])?
+            impl $(<$($params)*>)? BorrowOrShareLeak<$target> for $ty {
+                #[inline]
+                fn borrow_or_share_leak(self) -> &'static $target {
+                    let $this = self;
+                    $leak
+                }
+            }
+        )*
+    };
+}
+
+impl_bos_leak! {
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} &'static T => T, |this| this
+    #[cfg(feature = "alloc")]
+    {T: ?Sized} &'static mut T => T, |this| this
+
+    #[cfg(feature = "alloc")]
+    {T: 'static} Vec<T> => [T], |this| Box::leak(this.into_boxed_slice())
+    #[cfg(feature = "alloc")]
+    String => str, |this| Box::leak(this.into_boxed_str())
+    #[cfg(feature = "alloc")]
+    CString => CStr, |this| Box::leak(this.into_boxed_c_str())
+
+    #[cfg(feature = "std")]
+    OsString => OsStr, |this| Box::leak(this.into_boxed_os_str())
+    #[cfg(feature = "std")]
+    PathBuf => Path, |this| Box::leak(this.into_boxed_path())
+
+    #[cfg(feature = "alloc")]
+    {T: ?Sized + 'static} Box<T> => T, |this| Box::leak(this)
+
+    #[cfg(feature = "alloc")]
+    LazyStr<'static> => str, |this| match this {
+        LazyStr::Owned(s) => s.borrow_or_share_leak(),
+        LazyStr::Borrowed(s) => s,
+    }
+}
+
+/// Passes [`Cow::Borrowed`] through, and leaks [`Cow::Owned`] after
+/// converting it into a `Box<B>`.
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + ToOwned + 'static> BorrowOrShareLeak<B> for Cow<'static, B>
+where
+    Box<B>: From<B::Owned>,
+{
+    #[inline]
+    fn borrow_or_share_leak(self) -> &'static B {
+        match self {
+            Cow::Borrowed(b) => b,
+            Cow::Owned(o) => Box::leak(o.into()),
+        }
+    }
 }
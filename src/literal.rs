@@ -0,0 +1,54 @@
+/// Creates a string slice holder from a literal, sharing it for `'static`.
+///
+/// The macro expands to the given expression as a `&'static str`, which is
+/// the cheapest holder implementing [`Bos<str>`](crate::Bos). This keeps
+/// tests and examples terse, and the explicit type rules out accidentally
+/// passing a `String` or a temporary.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{bos_str, BorrowOrShare};
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let s: &'static str = as_str(&bos_str!("hello"));
+/// assert_eq!(s, "hello");
+/// assert_eq!(bos_str!(concat!("hello", ", world")), "hello, world");
+/// ```
+#[macro_export]
+macro_rules! bos_str {
+    ($s:expr) => {{
+        let s: &'static ::core::primitive::str = $s;
+        s
+    }};
+}
+
+/// Creates a byte slice holder from a literal, sharing it for `'static`.
+///
+/// The macro expands to the given expression as a `&'static [u8]`, which is
+/// the cheapest holder implementing [`Bos<[u8]>`](crate::Bos). Byte string
+/// literals and references to byte arrays are coerced to a slice.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{bos_bytes, BorrowOrShare};
+///
+/// fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+///     b.borrow_or_share()
+/// }
+///
+/// let b: &'static [u8] = bytes(&bos_bytes!(b"hello"));
+/// assert_eq!(b, b"hello");
+/// assert_eq!(bos_bytes!(&[1, 2, 3]), [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! bos_bytes {
+    ($b:expr) => {{
+        let b: &'static [::core::primitive::u8] = $b;
+        b
+    }};
+}
@@ -0,0 +1,121 @@
+use crate::{BorrowOrShare, BorrowsFrom, Bos, Shares};
+use core::{fmt, marker::PhantomData};
+
+/// An adapter borrowing data of type `T` through an intermediate target `M`.
+///
+/// A `Nested<B, M>` implements [`Bos<T>`] if `B: Bos<M>` and `M: Bos<T>`,
+/// e.g., `Nested<Box<Vec<u8>>, Vec<u8>>: Bos<[u8]>`. The intermediate
+/// target must be given explicitly, since a holder usually implements
+/// [`Bos`] for more than one target.
+///
+/// Its [`Bos`] implementation always borrows from `*self`, because the
+/// lifetime of the intermediate reference cannot be named in
+/// [`Ref`](Bos::Ref), and `T` must be `'static` for the same reason as
+/// with [`ViaBorrow`](crate::ViaBorrow). When the outer holder shares,
+/// as `&'a String` does, [`share`](Self::share) gets the data for `'a`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Nested};
+///
+/// fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+///     b.borrow_or_share()
+/// }
+///
+/// let nested = Nested::<_, Vec<u8>>::new(Box::new(vec![1, 2, 3]));
+/// assert_eq!(bytes(&nested), [1, 2, 3]);
+///
+/// let s = String::from("hello");
+/// let shared: &str = {
+///     let nested = Nested::<_, String>::new(&s);
+///     nested.share()
+/// };
+/// assert_eq!(shared, "hello");
+/// ```
+pub struct Nested<B, M: ?Sized> {
+    holder: B,
+    marker: PhantomData<fn() -> *const M>,
+}
+
+impl<B, M: ?Sized> Nested<B, M> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        Nested {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+
+    /// Shares data of type `T` for `'a` if the holder shares the intermediate
+    /// target for `'a`.
+    #[inline]
+    pub fn share<'a, T>(&self) -> &'a T
+    where
+        T: ?Sized,
+        B: Shares<'a, M>,
+        M: BorrowOrShare<'a, 'a, T> + 'a,
+    {
+        self.holder.share().borrow_or_share()
+    }
+}
+
+impl<B: Clone, M: ?Sized> Clone for Nested<B, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Nested::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, M: ?Sized> Copy for Nested<B, M> {}
+
+impl<B: fmt::Debug, M: ?Sized> fmt::Debug for Nested<B, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Nested").field(&self.holder).finish()
+    }
+}
+
+impl<T, M, B> Bos<T> for Nested<B, M>
+where
+    T: ?Sized + 'static,
+    M: ?Sized + Bos<T>,
+    B: Bos<M>,
+{
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        let mid = <B as BorrowOrShare<'_, '_, M>>::borrow_or_share(&this.holder);
+        <M as BorrowOrShare<'_, '_, T>>::borrow_or_share(mid)
+    }
+}
+
+impl<T, M, B> BorrowsFrom<T> for Nested<B, M>
+where
+    T: ?Sized + 'static,
+    M: ?Sized + Bos<T>,
+    B: Bos<M>,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<T, M, B> crate::BosToOwned<T> for Nested<B, M>
+where
+    T: ?Sized + alloc::borrow::ToOwned + 'static,
+    M: ?Sized + Bos<T>,
+    B: Bos<M>,
+{
+}
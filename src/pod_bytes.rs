@@ -0,0 +1,111 @@
+use crate::{internal::Map, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+use bytemuck::Pod;
+use core::{fmt, marker::PhantomData};
+
+/// An adapter viewing a slice of plain old data as bytes.
+///
+/// A `PodBytes<B, T>` holds a `B: Bos<[T]>`, where `T` is [`Pod`], and
+/// implements [`Bos<[u8]>`](Bos) by casting the slice borrowed or shared
+/// from the holder with [`bytemuck::cast_slice`]. Sharing is preserved:
+/// if `B` shares `[T]` for `'a`, then the adapter shares `[u8]` for `'a`
+/// too. The bytes are in native endianness.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, PodBytes};
+///
+/// fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+///     b.borrow_or_share()
+/// }
+///
+/// let data = PodBytes::<_, u16>::new(vec![0x0102, 0x0304]);
+/// let expected: Vec<u8> = [0x0102u16, 0x0304].iter().flat_map(|x| x.to_ne_bytes()).collect();
+/// assert_eq!(bytes(&data), expected);
+/// ```
+#[repr(transparent)]
+pub struct PodBytes<B, T> {
+    holder: B,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<B, T> PodBytes<B, T> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        PodBytes {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B, T> From<B> for PodBytes<B, T> {
+    #[inline]
+    fn from(holder: B) -> Self {
+        PodBytes::new(holder)
+    }
+}
+
+impl<B: Clone, T> Clone for PodBytes<B, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        PodBytes::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, T> Copy for PodBytes<B, T> {}
+
+impl<B: fmt::Debug, T> fmt::Debug for PodBytes<B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PodBytes").field(&self.holder).finish()
+    }
+}
+
+fn to_bytes<T: Pod>(slice: &[T]) -> &[u8] {
+    bytemuck::cast_slice(slice)
+}
+
+impl<B: Bos<[T]>, T: Pod> Bos<[u8]> for PodBytes<B, T> {
+    type Ref<'this> = <B::Ref<'this> as Map<[T]>>::Mapped<[u8]> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder).map(to_bytes)
+    }
+}
+
+impl<B: BorrowsFrom<[T]>, T: Pod> BorrowsFrom<[u8]> for PodBytes<B, T> {}
+impl<B: SharesWith<[T]>, T: Pod> SharesWith<[u8]> for PodBytes<B, T> {}
+impl<B: StableBos<[T]>, T: Pod> StableBos<[u8]> for PodBytes<B, T> {}
+
+impl<'a, B: Shares<'a, [T]>, T: Pod> Shares<'a, [u8]> for PodBytes<B, T> {
+    #[inline]
+    fn share(&self) -> &'a [u8] {
+        to_bytes(self.holder.share())
+    }
+}
+
+impl<B: TryBos<[T]>, T: Pod> TryBos<[u8]> for PodBytes<B, T> {
+    type Ref<'this> = <B::Ref<'this> as Map<[T]>>::Mapped<[u8]> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder).map(|r| r.map(to_bytes))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Bos<[T]>, T: Pod> crate::BosToOwned<[u8]> for PodBytes<B, T> {}
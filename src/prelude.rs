@@ -0,0 +1,34 @@
+//! A prelude for glob importing the traits of this crate.
+//!
+//! ```
+//! use borrow_or_share::prelude::*;
+//! ```
+//!
+//! This brings into scope the traits that are commonly used as bounds
+//! or that provide methods, so that method call syntax works for all of them:
+//! [`Bos`], [`BorrowOrShare`], [`BorrowOrShareExt`], [`BorrowFromSelf`],
+//! [`BosMut`], [`BorrowOrShareMut`], [`TryBos`], [`TryBorrowOrShare`],
+//! [`Shares`], [`GuardedBos`], [`BosGuard`], [`DynBos`],
+//! and, with the `alloc` feature, [`BosToOwned`], [`ToCow`],
+//! and [`BorrowOrShareLeak`].
+//!
+//! Types, functions, macros, and the marker traits [`BorrowsFrom`],
+//! [`SharesWith`], and [`StableBos`] are not included, nor is [`Ref`], whose name is likely
+//! to collide with [`core::cell::Ref`].
+//!
+//! New traits may be added to the prelude in minor releases. To avoid
+//! breakage from name collisions, prefer importing it in modules that do
+//! not define traits with the same names.
+//!
+//! [`BorrowsFrom`]: crate::BorrowsFrom
+//! [`SharesWith`]: crate::SharesWith
+//! [`StableBos`]: crate::StableBos
+//! [`Ref`]: crate::Ref
+
+pub use crate::{
+    BorrowFromSelf, BorrowOrShare, BorrowOrShareExt, BorrowOrShareMut, Bos, BosGuard, BosMut,
+    DynBos, GuardedBos, Shares, TryBorrowOrShare, TryBos,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::{BorrowOrShareLeak, BosToOwned, ToCow};
@@ -0,0 +1,128 @@
+use crate::{internal::Map, BorrowsFrom, Bos, Shares, SharesWith, TryBos};
+use core::fmt;
+
+/// An adapter projecting the data of a holder with a function.
+///
+/// A `Project<B, T, U>` holds a `B: Bos<T>` along with a function from
+/// `&T` to `&U`, and implements [`Bos<U>`] by applying the function
+/// to the data borrowed or shared from the holder. Since the function
+/// is generic over the lifetime, sharing is preserved: if `B` shares `T`
+/// for `'a`, then the projection shares `U` for `'a` too.
+///
+/// The projected type `U` must be `'static`, which is the case for
+/// most targets such as `str` and `[u8]`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Project};
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// fn name(user: &User) -> &str {
+///     &user.name
+/// }
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// fn share<'a>(user: &'a User) -> &'a str {
+///     as_str(&Project::new(user, name))
+/// }
+///
+/// let user = User { name: String::from("alice") };
+/// assert_eq!(share(&user), "alice");
+/// assert_eq!(as_str(&Project::new(Box::new(user), name)), "alice");
+/// ```
+pub struct Project<B, T: ?Sized, U: ?Sized> {
+    holder: B,
+    f: fn(&T) -> &U,
+}
+
+impl<B, T: ?Sized, U: ?Sized> Project<B, T, U> {
+    /// Creates a projection of the data of `holder` with `f`.
+    #[inline]
+    pub const fn new(holder: B, f: fn(&T) -> &U) -> Self {
+        Project { holder, f }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Returns the projection function.
+    #[inline]
+    pub fn func(&self) -> fn(&T) -> &U {
+        self.f
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B: Clone, T: ?Sized, U: ?Sized> Clone for Project<B, T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Project::new(self.holder.clone(), self.f)
+    }
+}
+
+impl<B: Copy, T: ?Sized, U: ?Sized> Copy for Project<B, T, U> {}
+
+impl<B: fmt::Debug, T: ?Sized, U: ?Sized> fmt::Debug for Project<B, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Project")
+            .field("holder", &self.holder)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Bos<T>, T: ?Sized, U: ?Sized + 'static> Bos<U> for Project<B, T, U> {
+    type Ref<'this> = <B::Ref<'this> as Map<T>>::Mapped<U> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder).map(this.f)
+    }
+}
+
+impl<B: BorrowsFrom<T>, T: ?Sized, U: ?Sized + 'static> BorrowsFrom<U> for Project<B, T, U> {}
+impl<B: SharesWith<T>, T: ?Sized, U: ?Sized + 'static> SharesWith<U> for Project<B, T, U> {}
+
+impl<'a, B, T, U> Shares<'a, U> for Project<B, T, U>
+where
+    B: Shares<'a, T>,
+    T: ?Sized + 'a,
+    U: ?Sized + 'static,
+{
+    #[inline]
+    fn share(&self) -> &'a U {
+        (self.f)(self.holder.share())
+    }
+}
+
+impl<B: TryBos<T>, T: ?Sized, U: ?Sized + 'static> TryBos<U> for Project<B, T, U> {
+    type Ref<'this> = <B::Ref<'this> as Map<T>>::Mapped<U> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.holder).map(|r| r.map(this.f))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B, T, U> crate::BosToOwned<U> for Project<B, T, U>
+where
+    B: Bos<T>,
+    T: ?Sized,
+    U: ?Sized + alloc::borrow::ToOwned + 'static,
+{
+}
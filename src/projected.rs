@@ -0,0 +1,103 @@
+use crate::{BorrowOrShare, Bos, Shares, SharesWith, StableBos, TryBos};
+use core::ops::Deref;
+
+/// A reference borrowed or shared from a holder once, for reuse.
+///
+/// Calling [`borrow_or_share`](BorrowOrShare::borrow_or_share) is usually
+/// cheap, and the compiler is likely to hoist it out of a loop anyway.
+/// A `Projected<'o, T>` makes the single projection explicit: it is
+/// created from a holder once, and then hands out the same `&'o T`
+/// for as long as it is alive.
+///
+/// It implements [`Bos<T>`] by sharing `'o`, like [`&'o T`](reference).
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::Projected;
+///
+/// let s = String::from("hello");
+/// let projected = Projected::<str>::new(&s);
+///
+/// let mut total = 0;
+/// for _ in 0..3 {
+///     total += projected.len();
+/// }
+/// assert_eq!(total, 15);
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Projected<'o, T: ?Sized>(&'o T);
+
+impl<'o, T: ?Sized> Projected<'o, T> {
+    /// Borrows or shares data of type `T` from `b`.
+    #[inline]
+    pub fn new<'i, B>(b: &'i B) -> Self
+    where
+        B: BorrowOrShare<'i, 'o, T> + ?Sized,
+    {
+        Projected(b.borrow_or_share())
+    }
+
+    /// Returns the reference.
+    #[inline]
+    pub fn get(self) -> &'o T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Clone for Projected<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Projected<'_, T> {}
+
+impl<'o, T: ?Sized> From<&'o T> for Projected<'o, T> {
+    #[inline]
+    fn from(r: &'o T) -> Self {
+        Projected(r)
+    }
+}
+
+impl<T: ?Sized> Deref for Projected<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'o, T: ?Sized> Bos<T> for Projected<'o, T> {
+    type Ref<'this> = &'o T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.0
+    }
+}
+
+impl<T: ?Sized> SharesWith<T> for Projected<'_, T> {}
+impl<T: ?Sized> StableBos<T> for Projected<'_, T> {}
+
+impl<'o, T: ?Sized> Shares<'o, T> for Projected<'o, T> {
+    #[inline]
+    fn share(&self) -> &'o T {
+        self.0
+    }
+}
+
+impl<'o, T: ?Sized> TryBos<T> for Projected<'o, T> {
+    type Ref<'this> = &'o T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + alloc::borrow::ToOwned> crate::BosToOwned<T> for Projected<'_, T> {}
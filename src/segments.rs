@@ -0,0 +1,84 @@
+use crate::{BorrowsFrom, Bos, TryBos};
+use std::net::Ipv6Addr;
+
+/// The eight 16-bit segments of an IPv6 address, held by value.
+///
+/// [`Ipv6Addr::segments`] returns the segments by value, so there is
+/// nothing inside an address to borrow them from. This type holds a copy
+/// of them instead, and implements [`Bos<[u16; 8]>`](Bos) and
+/// [`Bos<[u16]>`](Bos), borrowing from `*self`.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Segments};
+/// use std::net::Ipv6Addr;
+///
+/// fn segments<'i, 'o, B: BorrowOrShare<'i, 'o, [u16]>>(b: &'i B) -> &'o [u16] {
+///     b.borrow_or_share()
+/// }
+///
+/// let segs = Segments::from(Ipv6Addr::LOCALHOST);
+/// assert_eq!(segments(&segs), [0, 0, 0, 0, 0, 0, 0, 1]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Segments([u16; 8]);
+
+impl Segments {
+    /// Returns the segments as an array.
+    #[inline]
+    pub fn get(self) -> [u16; 8] {
+        self.0
+    }
+
+    /// Converts the segments back into an address.
+    #[inline]
+    pub fn to_addr(self) -> Ipv6Addr {
+        self.0.into()
+    }
+}
+
+impl From<Ipv6Addr> for Segments {
+    #[inline]
+    fn from(addr: Ipv6Addr) -> Self {
+        Segments(addr.segments())
+    }
+}
+
+impl From<Segments> for Ipv6Addr {
+    #[inline]
+    fn from(segs: Segments) -> Self {
+        segs.to_addr()
+    }
+}
+
+macro_rules! impl_bos_for_segments {
+    ($($target:ty)*) => {
+        $(
+            impl Bos<$target> for Segments {
+                type Ref<'this> = &'this $target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                    &this.0
+                }
+            }
+
+            impl BorrowsFrom<$target> for Segments {}
+
+            impl TryBos<$target> for Segments {
+                type Ref<'this> = &'this $target where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+                    Some(&this.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_bos_for_segments! {
+    [u16; 8]
+    [u16]
+}
@@ -0,0 +1,178 @@
+use crate::{bos_of, Bos};
+use core::{ffi::CStr, marker::PhantomData};
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "std")]
+use serde::ser::Error;
+#[cfg(feature = "std")]
+use std::{ffi::OsStr, path::Path};
+
+/// A type that can be serialized as the target of a holder.
+///
+/// This is used by [`SerializeVia`] and [`serialize_bos`], and is
+/// implemented on the following types:
+///
+/// | Type    | Serialized as                                   |
+/// |---------|-------------------------------------------------|
+/// | `str`   | a string                                        |
+/// | `[u8]`  | bytes, via [`Serializer::serialize_bytes`]      |
+/// | `CStr`  | bytes, without the nul terminator               |
+/// | `Path`  | a string, or an error if it is not valid UTF-8  |
+/// | `OsStr` | a string, or an error if it is not valid UTF-8  |
+///
+/// `Path` and `OsStr` are only supported with the `std` feature. They are
+/// not serialized lossily, so that no data is silently altered.
+pub trait SerializeTarget {
+    /// Serializes `self` with the given serializer.
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl SerializeTarget for str {
+    #[inline]
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl SerializeTarget for [u8] {
+    #[inline]
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+impl SerializeTarget for CStr {
+    #[inline]
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.to_bytes())
+    }
+}
+
+#[cfg(feature = "std")]
+impl SerializeTarget for OsStr {
+    #[inline]
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.to_str() {
+            Some(s) => serializer.serialize_str(s),
+            None => Err(S::Error::custom("OS string contains invalid UTF-8 characters")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl SerializeTarget for Path {
+    #[inline]
+    fn serialize_target<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.to_str() {
+            Some(s) => serializer.serialize_str(s),
+            None => Err(S::Error::custom("path contains invalid UTF-8 characters")),
+        }
+    }
+}
+
+/// Serializes the data of type `T` borrowed or shared from `b`.
+///
+/// This is meant to be used with `#[serde(serialize_with)]` on fields of
+/// a generic holder type, specifying the target with turbofish syntax.
+/// See [`SerializeTarget`] for how each target is serialized.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails, or if the data cannot be
+/// serialized as documented on [`SerializeTarget`].
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::Bos;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Node<T: Bos<str>> {
+///     #[serde(serialize_with = "borrow_or_share::serialize_bos::<str, _, _>")]
+///     name: T,
+/// }
+///
+/// let owned = Node { name: String::from("div") };
+/// let shared = Node { name: "div" };
+/// assert_eq!(serde_json::to_string(&owned).unwrap(), r#"{"name":"div"}"#);
+/// assert_eq!(serde_json::to_string(&shared).unwrap(), r#"{"name":"div"}"#);
+/// ```
+#[inline]
+pub fn serialize_bos<T, B, S>(b: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SerializeTarget + ?Sized,
+    B: Bos<T> + ?Sized,
+    S: Serializer,
+{
+    bos_of::<T, B>(b).serialize_target(serializer)
+}
+
+/// A wrapper serializing the data of type `T` borrowed or shared from a holder.
+///
+/// This implements [`Serialize`] with [`serialize_bos`], which lets a
+/// holder be serialized as its target where a [`Serialize`] type is
+/// expected. See [`SerializeTarget`] for how each target is serialized.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::SerializeVia;
+///
+/// let bytes = SerializeVia::<_, [u8]>::new(vec![1, 2, 3]);
+/// assert_eq!(serde_json::to_string(&bytes).unwrap(), "[1,2,3]");
+///
+/// let s = SerializeVia::<_, str>::new(String::from("hello"));
+/// assert_eq!(serde_json::to_string(&s).unwrap(), r#""hello""#);
+/// ```
+#[repr(transparent)]
+pub struct SerializeVia<B, T: ?Sized> {
+    holder: B,
+    marker: PhantomData<fn() -> *const T>,
+}
+
+impl<B, T: ?Sized> SerializeVia<B, T> {
+    /// Wraps the holder.
+    #[inline]
+    pub const fn new(holder: B) -> Self {
+        SerializeVia {
+            holder,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B, T: ?Sized> From<B> for SerializeVia<B, T> {
+    #[inline]
+    fn from(holder: B) -> Self {
+        SerializeVia::new(holder)
+    }
+}
+
+impl<B: Clone, T: ?Sized> Clone for SerializeVia<B, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SerializeVia::new(self.holder.clone())
+    }
+}
+
+impl<B: Copy, T: ?Sized> Copy for SerializeVia<B, T> {}
+
+impl<B: Bos<T>, T: SerializeTarget + ?Sized> Serialize for SerializeVia<B, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bos::<T, B, S>(&self.holder, serializer)
+    }
+}
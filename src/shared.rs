@@ -0,0 +1,109 @@
+use crate::{Bos, Shares, SharesWith, StableBos, TryBos};
+use core::ops::Deref;
+
+/// A reference that explicitly shares its data.
+///
+/// A `Shared<'a, T>` behaves the same as a [`&'a T`](reference): it
+/// implements [`Bos<T>`] by sharing `'a`. As a named type, however, it
+/// states the intent of sharing in public APIs, and reads clearly in
+/// signatures together with the [`Static<T>`] alias.
+///
+/// # Compared to `ForceBorrow`
+///
+/// [`ForceBorrow<B>`](crate::ForceBorrow) is the opposite of this type:
+/// it makes any holder borrow from `*self`, so that nothing it hands out
+/// escapes it. A `Shared<'a, T>` guarantees instead that the data always
+/// outlives the holder, which is what e.g. a registry keeping the data
+/// around needs.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Static};
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// fn register(names: &mut Vec<&'static str>, name: Text<Static<str>>) {
+///     names.push(name.as_str());
+/// }
+///
+/// let mut names = Vec::new();
+/// register(&mut names, Text("main".into()));
+/// assert_eq!(names, ["main"]);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Shared<'a, T: ?Sized>(pub &'a T);
+
+/// A reference sharing its data for `'static`.
+pub type Static<T> = Shared<'static, T>;
+
+impl<'a, T: ?Sized> Shared<'a, T> {
+    /// Returns the reference.
+    #[inline]
+    pub fn get(self) -> &'a T {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Clone for Shared<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Shared<'_, T> {}
+
+impl<'a, T: ?Sized> From<&'a T> for Shared<'a, T> {
+    #[inline]
+    fn from(r: &'a T) -> Self {
+        Shared(r)
+    }
+}
+
+impl<T: ?Sized> Deref for Shared<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> Bos<T> for Shared<'a, T> {
+    type Ref<'this> = &'a T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.0
+    }
+}
+
+impl<T: ?Sized> SharesWith<T> for Shared<'_, T> {}
+impl<T: ?Sized> StableBos<T> for Shared<'_, T> {}
+
+impl<'a, T: ?Sized> Shares<'a, T> for Shared<'a, T> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> TryBos<T> for Shared<'a, T> {
+    type Ref<'this> = &'a T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + alloc::borrow::ToOwned> crate::BosToOwned<T> for Shared<'_, T> {}
@@ -0,0 +1,148 @@
+use crate::{bos_of, internal::Map, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBos};
+use core::{fmt, str, str::Utf8Error};
+
+/// An adapter viewing the bytes of a holder as a string slice.
+///
+/// A `Utf8Bytes<B>` holds a `B: Bos<[u8]>` whose bytes have been checked
+/// to be valid UTF-8 on construction, and implements [`Bos<str>`] on top of
+/// it. Sharing is preserved: if `B` shares `[u8]` for `'a`, as `&'a [u8]`
+/// does, then the adapter shares `str` for `'a`.
+///
+/// Since this crate does not use `unsafe` code, the bytes are validated
+/// again on every call to [`borrow_or_share`](Bos::borrow_or_share),
+/// which takes linear time. If you call it repeatedly on a long string,
+/// consider keeping the returned reference, e.g., with
+/// [`Projected`](crate::Projected). The repeated validation cannot fail
+/// unless the holder returns different bytes on different calls, which no
+/// holder in this crate does, as they hand out shared references that
+/// keep their data immutable. Should it fail, it panics.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, Utf8Bytes};
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let owned = Utf8Bytes::try_new(b"hello".to_vec()).unwrap();
+/// assert_eq!(as_str(&owned), "hello");
+///
+/// let err = Utf8Bytes::try_new(&b"\xffhello"[..]).unwrap_err();
+/// assert_eq!(err.utf8_error().valid_up_to(), 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8Bytes<B>(B);
+
+/// The error returned when the bytes of a holder are not valid UTF-8.
+///
+/// The holder can be recovered with [`into_inner`](Self::into_inner).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotUtf8<B> {
+    holder: B,
+    error: Utf8Error,
+}
+
+impl<B> NotUtf8<B> {
+    /// Returns the underlying UTF-8 error.
+    #[inline]
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B> fmt::Display for NotUtf8<B> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: fmt::Debug> std::error::Error for NotUtf8<B> {}
+
+fn to_str(bytes: &[u8]) -> &str {
+    match str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => panic!("bytes no longer valid UTF-8: {e}"),
+    }
+}
+
+impl<B: Bos<[u8]>> Utf8Bytes<B> {
+    /// Wraps the holder if its bytes are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error containing the holder if its bytes are not valid UTF-8.
+    #[inline]
+    pub fn try_new(holder: B) -> Result<Self, NotUtf8<B>> {
+        match str::from_utf8(bos_of::<[u8], B>(&holder)) {
+            Ok(_) => Ok(Utf8Bytes(holder)),
+            Err(error) => Err(NotUtf8 { holder, error }),
+        }
+    }
+
+    /// Returns the bytes as a string slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are no longer valid UTF-8.
+    #[inline]
+    #[track_caller]
+    pub fn as_str(&self) -> &str {
+        to_str(bos_of::<[u8], B>(&self.0))
+    }
+}
+
+impl<B> Utf8Bytes<B> {
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.0
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B: Bos<[u8]>> Bos<str> for Utf8Bytes<B> {
+    type Ref<'this> = <B::Ref<'this> as Map<[u8]>>::Mapped<str> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.0).map(to_str)
+    }
+}
+
+impl<B: BorrowsFrom<[u8]>> BorrowsFrom<str> for Utf8Bytes<B> {}
+impl<B: SharesWith<[u8]>> SharesWith<str> for Utf8Bytes<B> {}
+impl<B: StableBos<[u8]>> StableBos<str> for Utf8Bytes<B> {}
+
+impl<'a, B: Shares<'a, [u8]>> Shares<'a, str> for Utf8Bytes<B> {
+    #[inline]
+    fn share(&self) -> &'a str {
+        to_str(self.0.share())
+    }
+}
+
+impl<B: TryBos<[u8]>> TryBos<str> for Utf8Bytes<B> {
+    type Ref<'this> = <B::Ref<'this> as Map<[u8]>>::Mapped<str> where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        B::try_borrow_or_share(&this.0).map(|r| r.map(to_str))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Bos<[u8]>> crate::BosToOwned<str> for Utf8Bytes<B> {}
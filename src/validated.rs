@@ -0,0 +1,193 @@
+use crate::{bos_of, BorrowsFrom, Bos, Shares, SharesWith, StableBos};
+use core::{fmt, marker::PhantomData};
+
+/// A check that data of type `T` upholds some invariant.
+///
+/// This is used by [`Validated`] to check the data of a holder on
+/// construction. The crate provides [`AsciiOnly`] as an example.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{Validated, Validator};
+///
+/// struct Sorted;
+///
+/// impl Validator<[i32]> for Sorted {
+///     type Error = usize;
+///
+///     fn validate(slice: &[i32]) -> Result<(), usize> {
+///         match slice.windows(2).position(|w| w[0] > w[1]) {
+///             Some(i) => Err(i + 1),
+///             None => Ok(()),
+///         }
+///     }
+/// }
+///
+/// assert!(Validated::<_, Sorted>::try_new([1, 2, 3]).is_ok());
+/// assert_eq!(Validated::<_, Sorted>::try_new([1, 3, 2]).err(), Some(2));
+/// ```
+pub trait Validator<T: ?Sized> {
+    /// The error returned when the data is invalid.
+    type Error;
+
+    /// Checks the data.
+    fn validate(data: &T) -> Result<(), Self::Error>;
+}
+
+/// A validator accepting string slices that only contain ASCII characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiOnly;
+
+/// The error returned by [`AsciiOnly`] for a string slice with non-ASCII
+/// characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NotAscii {
+    valid_up_to: usize,
+}
+
+impl NotAscii {
+    /// Returns the byte index of the first non-ASCII character.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl fmt::Display for NotAscii {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-ASCII character at index {}", self.valid_up_to)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotAscii {}
+
+impl Validator<str> for AsciiOnly {
+    type Error = NotAscii;
+
+    #[inline]
+    fn validate(s: &str) -> Result<(), NotAscii> {
+        match s.bytes().position(|b| !b.is_ascii()) {
+            Some(valid_up_to) => Err(NotAscii { valid_up_to }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An adapter holding data that has been checked by a [`Validator`].
+///
+/// A `Validated<B, V>` can only be created by [`try_new`](Self::try_new),
+/// which runs `V` against the data borrowed or shared from the holder. It
+/// then implements [`Bos<T>`] by forwarding to the holder, preserving its
+/// sharing behavior. This is a skeleton for wrapper types that uphold
+/// an invariant, such as a syntax or a charset, checked once on construction.
+///
+/// The invariant is only guaranteed if the holder returns the same data on
+/// every call, which is the case for all holders in this crate.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{AsciiOnly, BorrowOrShare, Validated};
+///
+/// type Ident<B> = Validated<B, AsciiOnly>;
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let ident = Ident::try_new(String::from("name")).unwrap();
+/// assert_eq!(as_str(&ident), "name");
+///
+/// let err = Ident::try_new("naïve").unwrap_err();
+/// assert_eq!(err.valid_up_to(), 2);
+/// ```
+pub struct Validated<B, V> {
+    holder: B,
+    marker: PhantomData<fn() -> V>,
+}
+
+impl<B, V> Validated<B, V> {
+    /// Wraps the holder if its data passes the validator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the validator if the data does not pass it.
+    #[inline]
+    pub fn try_new<T>(holder: B) -> Result<Self, V::Error>
+    where
+        T: ?Sized,
+        B: Bos<T>,
+        V: Validator<T>,
+    {
+        V::validate(bos_of::<T, B>(&holder))?;
+        Ok(Validated {
+            holder,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the holder.
+    #[inline]
+    pub fn get(&self) -> &B {
+        &self.holder
+    }
+
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.holder
+    }
+}
+
+impl<B: Clone, V> Clone for Validated<B, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Validated {
+            holder: self.holder.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<B: Copy, V> Copy for Validated<B, V> {}
+
+impl<B: fmt::Debug, V> fmt::Debug for Validated<B, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validated").field(&self.holder).finish()
+    }
+}
+
+impl<T: ?Sized, B: Bos<T>, V: Validator<T>> Bos<T> for Validated<B, V> {
+    type Ref<'this> = B::Ref<'this> where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        B::borrow_or_share(&this.holder)
+    }
+}
+
+impl<T: ?Sized, B: BorrowsFrom<T>, V: Validator<T>> BorrowsFrom<T> for Validated<B, V> {}
+impl<T: ?Sized, B: SharesWith<T>, V: Validator<T>> SharesWith<T> for Validated<B, V> {}
+impl<T: ?Sized, B: StableBos<T>, V: Validator<T>> StableBos<T> for Validated<B, V> {}
+
+impl<'a, T: ?Sized, B: Shares<'a, T>, V: Validator<T>> Shares<'a, T> for Validated<B, V> {
+    #[inline]
+    fn share(&self) -> &'a T {
+        self.holder.share()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, V> crate::BosToOwned<T> for Validated<B, V>
+where
+    T: ?Sized + alloc::borrow::ToOwned,
+    B: crate::BosToOwned<T>,
+    V: Validator<T>,
+{
+    #[inline]
+    fn into_owned_target(self) -> T::Owned {
+        self.holder.into_owned_target()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{BorrowsFrom, Bos, BosMut, TryBos};
+use core::ops::{Deref, DerefMut};
+
+/// A wrapper implementing [`Bos`] on any [`AsRef`] type, borrowing the target.
+///
+/// This is the [`AsRef`] counterpart of [`ViaBorrow`](crate::ViaBorrow),
+/// for types such as path-like values and byte buffers that implement
+/// [`AsRef<T>`] but not [`Borrow<T>`](core::borrow::Borrow). It borrows
+/// from `*self`, and the target must be `'static` for the same reason.
+/// [`BosMut`] is also implemented when `B` implements [`AsMut`] as well.
+///
+/// A type often implements [`AsRef`] for several targets, e.g., `String`
+/// for `str`, `[u8]`, `OsStr`, and `Path`, and so does the wrapper
+/// implement [`Bos`] for each of them. Bound on a specific target,
+/// or specify it with turbofish syntax, to pick one.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{bos_of, BorrowOrShare, ViaAsRef};
+/// use std::path::Path;
+///
+/// fn path<'i, 'o, B: BorrowOrShare<'i, 'o, Path>>(b: &'i B) -> &'o Path {
+///     b.borrow_or_share()
+/// }
+///
+/// let s = ViaAsRef(String::from("/tmp"));
+/// assert_eq!(path(&s), Path::new("/tmp"));
+/// assert_eq!(bos_of::<[u8], _>(&s), b"/tmp");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ViaAsRef<B>(pub B);
+
+impl<B> ViaAsRef<B> {
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B> From<B> for ViaAsRef<B> {
+    #[inline]
+    fn from(b: B) -> Self {
+        ViaAsRef(b)
+    }
+}
+
+impl<B> Deref for ViaAsRef<B> {
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B> DerefMut for ViaAsRef<B> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut B {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized + 'static, B: AsRef<T>> Bos<T> for ViaAsRef<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.0.as_ref()
+    }
+}
+
+impl<T: ?Sized + 'static, B: AsRef<T>> BorrowsFrom<T> for ViaAsRef<B> {}
+
+impl<T: ?Sized + 'static, B: AsRef<T>> TryBos<T> for ViaAsRef<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.0.as_ref())
+    }
+}
+
+impl<T: ?Sized + 'static, B: AsRef<T> + AsMut<T>> BosMut<T> for ViaAsRef<B> {
+    type RefMut<'this> = &'this mut T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        this.0.as_mut()
+    }
+}
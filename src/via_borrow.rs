@@ -0,0 +1,198 @@
+use crate::{BorrowsFrom, Bos, BosMut, TryBos};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    ops::{Deref, DerefMut},
+};
+
+/// A wrapper implementing [`Bos`] on any [`Borrow`] type, borrowing the target.
+///
+/// This crate does not implement [`Bos<T>`] on every `B: Borrow<T>`, since
+/// such a blanket implementation would rule out the sharing implementations
+/// on references. Wrapping a holder that only implements [`Borrow<T>`] in
+/// `ViaBorrow` gives it [`Bos<T>`], which is useful for foreign types on
+/// which you cannot implement [`Bos`] yourself. Like most owned holders,
+/// it borrows from `*self`.
+///
+/// The target must be `'static`, because it does not appear in the type
+/// of the wrapper and thus cannot be proven to outlive the borrow otherwise.
+/// [`BosMut`] is also implemented when `B` implements [`BorrowMut`].
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, ViaBorrow};
+/// use std::borrow::Borrow;
+///
+/// // A foreign type that only implements `Borrow<str>`.
+/// struct Name(Box<str>);
+///
+/// impl Borrow<str> for Name {
+///     fn borrow(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// struct Text<T>(T);
+///
+/// impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+///     fn as_str(&'i self) -> &'o str {
+///         self.0.borrow_or_share()
+///     }
+/// }
+///
+/// let text = Text(ViaBorrow(Name("hello".into())));
+/// assert_eq!(text.as_str(), "hello");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ViaBorrow<B>(pub B);
+
+impl<B> ViaBorrow<B> {
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B> From<B> for ViaBorrow<B> {
+    #[inline]
+    fn from(b: B) -> Self {
+        ViaBorrow(b)
+    }
+}
+
+impl<B> Deref for ViaBorrow<B> {
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B> DerefMut for ViaBorrow<B> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut B {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized + 'static, B: Borrow<T>> Bos<T> for ViaBorrow<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        this.0.borrow()
+    }
+}
+
+impl<T: ?Sized + 'static, B: Borrow<T>> BorrowsFrom<T> for ViaBorrow<B> {}
+
+impl<T: ?Sized + 'static, B: Borrow<T>> TryBos<T> for ViaBorrow<B> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(this.0.borrow())
+    }
+}
+
+impl<T: ?Sized + 'static, B: BorrowMut<T>> BosMut<T> for ViaBorrow<B> {
+    type RefMut<'this> = &'this mut T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        this.0.borrow_mut()
+    }
+}
+
+/// Implements [`Bos`] on types that implement [`Borrow`], borrowing the target.
+///
+/// This generates the same implementations that this crate uses for its
+/// owned holders, so that you need not write out the boilerplate by hand.
+/// For each entry of the form `Type => Target;`, the macro implements
+/// [`Bos<Target>`], [`BorrowsFrom<Target>`], and [`TryBos<Target>`] on
+/// `Type` with [`Borrow::borrow`]. Generic parameters may be given in
+/// braces before the type, and attributes such as `#[cfg(...)]` before
+/// an entry apply to all of its implementations.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{impl_bos_via_borrow, BorrowOrShare};
+/// use std::borrow::Borrow;
+///
+/// struct MyString(String);
+///
+/// impl Borrow<str> for MyString {
+///     fn borrow(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// impl_bos_via_borrow! {
+///     MyString => str;
+/// }
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// assert_eq!(as_str(&MyString(String::from("hello"))), "hello");
+/// ```
+///
+/// With generic parameters and attributes:
+///
+/// ```
+/// use borrow_or_share::{impl_bos_via_borrow, BorrowOrShare};
+/// use std::borrow::Borrow;
+///
+/// struct MyVec<T>(Vec<T>);
+///
+/// impl<T> Borrow<[T]> for MyVec<T> {
+///     fn borrow(&self) -> &[T] {
+///         &self.0
+///     }
+/// }
+///
+/// impl_bos_via_borrow! {
+///     #[cfg(not(feature = "unused"))]
+///     {T} MyVec<T> => [T];
+/// }
+///
+/// fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [i32]>>(b: &'i B) -> &'o [i32] {
+///     b.borrow_or_share()
+/// }
+///
+/// assert_eq!(slice(&MyVec(vec![1, 2, 3])), [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! impl_bos_via_borrow {
+    ($($(#[$attr:meta])* $({$($params:tt)*})? $ty:ty => $target:ty);* $(;)?) => {
+        $(
+            $(#[$attr])*
+            impl $(<$($params)*>)? $crate::Bos<$target> for $ty {
+                type Ref<'this> = &'this $target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                    ::core::borrow::Borrow::<$target>::borrow(this)
+                }
+            }
+
+            $(#[$attr])*
+            impl $(<$($params)*>)? $crate::BorrowsFrom<$target> for $ty {}
+
+            $(#[$attr])*
+            impl $(<$($params)*>)? $crate::TryBos<$target> for $ty {
+                type Ref<'this> = &'this $target where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+                    ::core::option::Option::Some(::core::borrow::Borrow::<$target>::borrow(this))
+                }
+            }
+        )*
+    };
+}
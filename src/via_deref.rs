@@ -0,0 +1,167 @@
+use crate::{Bos, BosMut, BorrowsFrom, TryBos};
+use core::ops::{Deref, DerefMut};
+
+/// A wrapper implementing [`Bos`] on any [`Deref`] type, borrowing the target.
+///
+/// This crate does not implement [`Bos<B::Target>`] on every `B: Deref`,
+/// since coherence would treat such a blanket implementation as overlapping
+/// with those on `&T` and `Box<T>`. Wrapping a holder in `ViaDeref` gives it
+/// [`Bos<B::Target>`], borrowing from `*self`. A type of your own may use
+/// the [`bos_for_deref_target!`](crate::bos_for_deref_target) macro instead.
+///
+/// [`BosMut`] is also implemented when `B` implements [`DerefMut`].
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, ViaDeref};
+/// use std::ops::Deref;
+///
+/// struct Shared(Box<str>);
+///
+/// impl Deref for Shared {
+///     type Target = str;
+///
+///     fn deref(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let s = ViaDeref(Shared("hello".into()));
+/// assert_eq!(as_str(&s), "hello");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ViaDeref<B>(pub B);
+
+impl<B> ViaDeref<B> {
+    /// Unwraps the holder.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.0
+    }
+}
+
+impl<B> From<B> for ViaDeref<B> {
+    #[inline]
+    fn from(b: B) -> Self {
+        ViaDeref(b)
+    }
+}
+
+impl<B> Deref for ViaDeref<B> {
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B> DerefMut for ViaDeref<B> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut B {
+        &mut self.0
+    }
+}
+
+impl<B: Deref> Bos<B::Target> for ViaDeref<B> {
+    type Ref<'this> = &'this B::Target where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        &this.0
+    }
+}
+
+impl<B: Deref> BorrowsFrom<B::Target> for ViaDeref<B> {}
+
+impl<B: Deref> TryBos<B::Target> for ViaDeref<B> {
+    type Ref<'this> = &'this B::Target where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(&this.0)
+    }
+}
+
+impl<B: DerefMut> BosMut<B::Target> for ViaDeref<B> {
+    type RefMut<'this> = &'this mut B::Target where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share_mut(this: &mut Self) -> Self::RefMut<'_> {
+        &mut this.0
+    }
+}
+
+/// Implements [`Bos`] on types that implement [`Deref`], borrowing the target.
+///
+/// This is an alternative to [`ViaDeref`] for when you own the type
+/// and would rather not wrap it. For each given type, the macro implements
+/// [`Bos`], [`BorrowsFrom`], and [`TryBos`] with `<Type as Deref>::Target`
+/// as the target. Generic parameters may be given in braces before the type.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{bos_for_deref_target, BorrowOrShare};
+/// use std::ops::Deref;
+///
+/// struct Name(String);
+///
+/// impl Deref for Name {
+///     type Target = str;
+///
+///     fn deref(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// struct Bytes<T>(Vec<T>);
+///
+/// impl<T> Deref for Bytes<T> {
+///     type Target = [T];
+///
+///     fn deref(&self) -> &[T] {
+///         &self.0
+///     }
+/// }
+///
+/// bos_for_deref_target!(Name, {T} Bytes<T>);
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// assert_eq!(as_str(&Name(String::from("hello"))), "hello");
+/// ```
+#[macro_export]
+macro_rules! bos_for_deref_target {
+    ($($({$($params:tt)*})? $ty:ty),* $(,)?) => {
+        $(
+            impl $(<$($params)*>)? $crate::Bos<<$ty as ::core::ops::Deref>::Target> for $ty {
+                type Ref<'this> = &'this <$ty as ::core::ops::Deref>::Target where Self: 'this;
+
+                #[inline]
+                fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+                    ::core::ops::Deref::deref(this)
+                }
+            }
+
+            impl $(<$($params)*>)? $crate::BorrowsFrom<<$ty as ::core::ops::Deref>::Target> for $ty {}
+
+            impl $(<$($params)*>)? $crate::TryBos<<$ty as ::core::ops::Deref>::Target> for $ty {
+                type Ref<'this> = &'this <$ty as ::core::ops::Deref>::Target where Self: 'this;
+
+                #[inline]
+                fn try_borrow_or_share(this: &Self) -> ::core::option::Option<Self::Ref<'_>> {
+                    ::core::option::Option::Some(::core::ops::Deref::deref(this))
+                }
+            }
+        )*
+    };
+}
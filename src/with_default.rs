@@ -0,0 +1,128 @@
+use crate::{bos_of, BorrowsFrom, Bos, StableBos, TryBos};
+use core::fmt;
+
+/// An optional holder falling back to a static default.
+///
+/// This is useful for configuration fields of type `Option<B>` that have
+/// a documented default. A `WithDefault<B, T>` implements [`Bos<T>`] by
+/// borrowing from the holder if there is one, or returning the default
+/// otherwise.
+///
+/// Although the default could be shared for `'static`, a single
+/// implementation must cover both cases, so the data is always borrowed
+/// from `*self`, even if the holder itself shares.
+///
+/// # Examples
+///
+/// ```
+/// use borrow_or_share::{BorrowOrShare, WithDefault};
+///
+/// fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+///     b.borrow_or_share()
+/// }
+///
+/// let name = WithDefault::new(None::<String>, "unnamed");
+/// assert!(name.is_default());
+/// assert_eq!(as_str(&name), "unnamed");
+///
+/// let name = WithDefault::some(String::from("server"), "unnamed");
+/// assert_eq!(as_str(&name), "server");
+/// ```
+pub struct WithDefault<B, T: ?Sized + 'static> {
+    holder: Option<B>,
+    default: &'static T,
+}
+
+impl<B, T: ?Sized + 'static> WithDefault<B, T> {
+    /// Creates a holder falling back to `default` if `holder` is `None`.
+    #[inline]
+    pub const fn new(holder: Option<B>, default: &'static T) -> Self {
+        WithDefault { holder, default }
+    }
+
+    /// Creates a holder with a value, which is used instead of `default`.
+    #[inline]
+    pub const fn some(holder: B, default: &'static T) -> Self {
+        WithDefault::new(Some(holder), default)
+    }
+
+    /// Creates a holder without a value, falling back to `default`.
+    #[inline]
+    pub const fn none(default: &'static T) -> Self {
+        WithDefault::new(None, default)
+    }
+
+    /// Returns `true` if there is no holder and the default is used.
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        self.holder.is_none()
+    }
+
+    /// Returns a reference to the holder, if any.
+    #[inline]
+    pub fn get(&self) -> Option<&B> {
+        self.holder.as_ref()
+    }
+
+    /// Returns the default.
+    #[inline]
+    pub fn default_value(&self) -> &'static T {
+        self.default
+    }
+
+    /// Unwraps the holder, discarding the default.
+    #[inline]
+    pub fn into_inner(self) -> Option<B> {
+        self.holder
+    }
+}
+
+impl<B: Clone, T: ?Sized + 'static> Clone for WithDefault<B, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        WithDefault::new(self.holder.clone(), self.default)
+    }
+}
+
+impl<B: Copy, T: ?Sized + 'static> Copy for WithDefault<B, T> {}
+
+impl<B: fmt::Debug, T: ?Sized + fmt::Debug + 'static> fmt::Debug for WithDefault<B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithDefault")
+            .field("holder", &self.holder)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+impl<T: ?Sized + 'static, B: Bos<T>> Bos<T> for WithDefault<B, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        match &this.holder {
+            Some(holder) => bos_of::<T, B>(holder),
+            None => this.default,
+        }
+    }
+}
+
+impl<T: ?Sized + 'static, B: Bos<T>> BorrowsFrom<T> for WithDefault<B, T> {}
+impl<T: ?Sized + 'static, B: StableBos<T>> StableBos<T> for WithDefault<B, T> {}
+
+impl<T: ?Sized + 'static, B: Bos<T>> TryBos<T> for WithDefault<B, T> {
+    type Ref<'this> = &'this T where Self: 'this;
+
+    #[inline]
+    fn try_borrow_or_share(this: &Self) -> Option<Self::Ref<'_>> {
+        Some(Bos::borrow_or_share(this))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B> crate::BosToOwned<T> for WithDefault<B, T>
+where
+    T: ?Sized + alloc::borrow::ToOwned + 'static,
+    B: Bos<T>,
+{
+}
@@ -0,0 +1,1320 @@
+#![cfg(feature = "alloc")]
+
+use borrow_or_share::{
+    BorrowOrShare, BorrowOrShareLeak, BorrowsFrom, Bos, BosCell, BosToOwned, Contiguous, DynBos,
+    FromBorrowOrShare, LazyStr, SharesWith, StableBos, ToCow, TryBorrowOrShare,
+};
+use std::{borrow::Cow, collections::VecDeque, mem::ManuallyDrop, rc::Rc, sync::Arc};
+
+fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [i32]>>(b: &'i B) -> &'o [i32] {
+    b.borrow_or_share()
+}
+
+#[test]
+fn vec_deque_contiguous() {
+    let mut deque = VecDeque::from(vec![1, 2]);
+    deque.push_back(3);
+    assert_eq!(slice(&deque), [1, 2, 3]);
+}
+
+#[test]
+#[should_panic = "`VecDeque` is not contiguous"]
+fn vec_deque_wrapped() {
+    let mut deque = VecDeque::with_capacity(4);
+    deque.push_back(1);
+    deque.push_back(2);
+    deque.push_front(0);
+    assert!(!deque.as_slices().1.is_empty());
+
+    slice(&deque);
+}
+
+#[test]
+fn vec_deque_wrapped_to_owned() {
+    let mut deque = VecDeque::with_capacity(4);
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);
+    assert!(!deque.as_slices().1.is_empty());
+
+    assert_eq!(BosToOwned::<[i32]>::to_owned_target(&deque), [1, 2, 3]);
+    assert_eq!(BosToOwned::<[i32]>::into_owned_target(deque), [1, 2, 3]);
+}
+
+#[test]
+fn vec_deque_made_contiguous() {
+    let mut deque = VecDeque::with_capacity(4);
+    deque.push_back(1);
+    deque.push_back(2);
+    deque.push_front(0);
+    deque.make_contiguous();
+
+    assert_eq!(slice(&deque), [0, 1, 2]);
+}
+
+fn str<'i, 'o, B: BorrowOrShare<'i, 'o, str> + ?Sized>(b: &'i B) -> &'o str {
+    b.borrow_or_share()
+}
+
+#[test]
+fn shared_vec_and_string() {
+    let v = vec![1, 2, 3];
+    assert_eq!(slice(&Rc::new(v.clone())), [1, 2, 3]);
+    assert_eq!(slice(&Arc::new(v)), [1, 2, 3]);
+
+    let s = String::from("hello");
+    assert_eq!(str(&Rc::new(s.clone())), "hello");
+    assert_eq!(str(&Arc::new(s)), "hello");
+}
+
+#[test]
+fn shared_slice_and_str() {
+    fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    let rc: Rc<[u8]> = Rc::from(&b"abc"[..]);
+    let arc: Arc<[u8]> = Arc::from(&b"abc"[..]);
+    borrows::<[u8], _>(&rc);
+    borrows::<[u8], _>(&arc);
+    assert_eq!(bytes(&rc), b"abc");
+    assert_eq!(bytes(&arc), b"abc");
+    assert_eq!(rc.into_owned_target(), b"abc");
+
+    let rc: Rc<str> = Rc::from("hello");
+    let arc: Arc<str> = Arc::from("hello");
+    borrows::<str, _>(&rc);
+    borrows::<[u8], _>(&arc);
+    assert_eq!(str(&rc), "hello");
+    assert_eq!(str(&arc), "hello");
+    assert_eq!(bytes(&rc), b"hello");
+    assert_eq!(bytes(&arc), b"hello");
+    assert_eq!(BosToOwned::<[u8]>::to_owned_target(&arc), b"hello");
+}
+
+#[test]
+fn from_borrow_or_share() {
+    fn round_trip<'i, 'o, T, B>(b: &'i B) -> B
+    where
+        T: ?Sized + 'o,
+        B: BorrowOrShare<'i, 'o, T> + FromBorrowOrShare<'o, T>,
+    {
+        B::from_ref(b.borrow_or_share())
+    }
+
+    assert_eq!(round_trip::<str, _>(&String::from("abc")), "abc");
+    assert_eq!(round_trip::<[u8], _>(&vec![1u8, 2, 3]), [1, 2, 3]);
+
+    assert_eq!(&*<Box<str>>::from_ref("abc"), "abc");
+    assert_eq!(&*<Rc<[u8]>>::from_ref(&[1, 2]), [1, 2]);
+    assert_eq!(&*<Arc<str>>::from_ref("abc"), "abc");
+    assert!(matches!(<Cow<'_, str>>::from_ref("abc"), Cow::Borrowed("abc")));
+}
+
+#[test]
+fn vec_deque_try() {
+    let mut deque = VecDeque::with_capacity(4);
+    deque.push_back(1);
+    deque.push_back(2);
+    deque.push_front(0);
+    assert_eq!(TryBorrowOrShare::<[i32]>::try_borrow_or_share(&deque), None);
+
+    deque.make_contiguous();
+    assert_eq!(
+        TryBorrowOrShare::<[i32]>::try_borrow_or_share(&deque),
+        Some(&[0, 1, 2][..])
+    );
+}
+
+#[test]
+fn lazy_str() {
+    fn shared<'a>(s: &LazyStr<'a>) -> Option<&'a str> {
+        s.shared()
+    }
+
+    let owned = LazyStr::from(String::from("owned"));
+    assert_eq!(str(&owned), "owned");
+    assert_eq!(owned.shared(), None);
+
+    let data = String::from("borrowed");
+    let s = {
+        let borrowed = LazyStr::from(data.as_str());
+        assert_eq!(str(&borrowed), "borrowed");
+        shared(&borrowed)
+    };
+    assert_eq!(s, Some("borrowed"));
+
+    let n = 42;
+    assert!(matches!(LazyStr::from(format_args!("{n}")), LazyStr::Owned(s) if s == "42"));
+    assert!(matches!(LazyStr::from(format_args!("42")), LazyStr::Borrowed("42")));
+}
+
+#[test]
+fn lazy_str_by_content() {
+    use std::collections::{hash_map::DefaultHasher, BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    fn hash(s: &LazyStr<'_>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let owned = LazyStr::Owned(String::from("hello"));
+    let borrowed = LazyStr::Borrowed("hello");
+    assert_eq!(owned, borrowed);
+    assert_eq!(owned.cmp(&borrowed), std::cmp::Ordering::Equal);
+    assert_eq!(hash(&owned), hash(&borrowed));
+
+    assert!(LazyStr::Borrowed("a") < LazyStr::Owned(String::from("b")));
+    assert!(LazyStr::Owned(String::from("a")) < LazyStr::Borrowed("b"));
+
+    let hashed: HashSet<_> = [owned.clone(), borrowed.clone()].into_iter().collect();
+    assert_eq!(hashed.len(), 1);
+    let ordered: BTreeSet<_> = [owned, borrowed].into_iter().collect();
+    assert_eq!(ordered.len(), 1);
+}
+
+#[test]
+fn dyn_bos() {
+    let holders: Vec<Box<dyn DynBos<str> + Send + Sync>> = vec![
+        Box::new(String::from("owned")),
+        Box::new("static"),
+        Box::new(Arc::<str>::from("counted")),
+    ];
+
+    let strs: Vec<&str> = holders.iter().map(|b| str(&**b)).collect();
+    assert_eq!(strs, ["owned", "static", "counted"]);
+
+    let strs: Vec<&str> = holders.iter().map(str).collect();
+    assert_eq!(strs, ["owned", "static", "counted"]);
+
+    let nested: Box<dyn DynBos<str>> = Box::new(holders.into_iter().next().unwrap());
+    assert_eq!(str(&nested), "owned");
+}
+
+#[test]
+fn markers() {
+    fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+    fn shares<T: ?Sized, B: SharesWith<T>>(_: &B) {}
+
+    borrows::<str, _>(&String::new());
+    borrows::<[u8], _>(&vec![0u8]);
+    borrows::<str, _>(&Arc::<str>::from(""));
+    borrows::<str, _>(&LazyStr::Borrowed(""));
+    borrows::<str, _>(&BosCell::new(String::new()));
+
+    shares::<str, _>(&"");
+    shares::<str, _>(&BosCell::new(""));
+}
+
+#[test]
+fn vec_ref() {
+    struct Buf<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [i32]>> Buf<T> {
+        fn as_slice(&'i self) -> &'o [i32] {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn share<'a>(buf: &Buf<&'a Vec<i32>>) -> &'a [i32] {
+        buf.as_slice()
+    }
+
+    let v = vec![1, 2, 3];
+    let shared = {
+        let buf = Buf(&v);
+        share(&buf)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+}
+
+#[test]
+fn string_ref() {
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn share<'a>(text: &Text<&'a String>) -> &'a str {
+        text.as_str()
+    }
+
+    let s = String::from("hello");
+    let shared = {
+        let text = Text(&s);
+        share(&text)
+    };
+    assert_eq!(shared, "hello");
+}
+
+#[test]
+#[allow(clippy::borrowed_box)]
+fn boxed_ref() {
+    struct Buf<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [i32]>> Buf<T> {
+        fn as_slice(&'i self) -> &'o [i32] {
+            self.0.borrow_or_share()
+        }
+    }
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn share_slice<'a>(buf: &Buf<&'a Box<[i32]>>) -> &'a [i32] {
+        buf.as_slice()
+    }
+
+    fn share_str<'a>(text: &Text<&'a Box<str>>) -> &'a str {
+        text.as_str()
+    }
+
+    let b: Box<[i32]> = Box::new([1, 2, 3]);
+    let shared = {
+        let buf = Buf(&b);
+        share_slice(&buf)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+
+    let b: Box<str> = Box::from("hello");
+    let shared = {
+        let text = Text(&b);
+        share_str(&text)
+    };
+    assert_eq!(shared, "hello");
+    assert_eq!(BosToOwned::<str>::into_owned_target(&b), "hello");
+}
+
+#[test]
+fn borrows_in_place() {
+    let s = String::from("hello");
+    assert!(std::ptr::eq(str(&s), s.as_str()));
+    assert!(std::ptr::eq(str(&s.as_str()), s.as_str()));
+    assert!(std::ptr::eq(str(&&s), s.as_str()));
+    assert!(std::ptr::eq(str(&Cow::Borrowed(&*s)), s.as_str()));
+
+    let v = vec![1, 2, 3];
+    assert!(std::ptr::eq(slice(&v), v.as_slice()));
+    assert!(std::ptr::eq(slice(&&v), v.as_slice()));
+}
+
+#[test]
+fn into_owned_target() {
+    let s = String::from("hello");
+    let ptr = s.as_ptr();
+    let owned = BosToOwned::<str>::into_owned_target(s);
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let cow: Cow<'_, str> = Cow::Owned(String::from("hello"));
+    let ptr = cow.as_ptr();
+    let owned = BosToOwned::<str>::into_owned_target(cow);
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let boxed: Box<str> = "hello".into();
+    let ptr = boxed.as_ptr();
+    let owned = BosToOwned::<str>::into_owned_target(boxed);
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let v = vec![1, 2, 3];
+    let ptr = v.as_ptr();
+    let owned = BosToOwned::<[i32]>::into_owned_target(v);
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let rc = Rc::new(String::from("hello"));
+    let ptr = rc.as_ptr();
+    let owned = BosToOwned::<str>::into_owned_target(rc);
+    assert_eq!(owned.as_ptr(), ptr);
+
+    let rc = Rc::new(String::from("hello"));
+    let other = rc.clone();
+    let owned = BosToOwned::<str>::into_owned_target(rc);
+    assert_eq!(owned, "hello");
+    assert_ne!(owned.as_ptr(), other.as_ptr());
+}
+
+#[test]
+fn to_owned_target() {
+    let s = "hello";
+    let owned = BosToOwned::<str>::to_owned_target(&s);
+    assert_eq!(owned, s);
+    assert_ne!(owned.as_ptr(), s.as_ptr());
+
+    let s = String::from("hello");
+    let owned = s.to_owned_target();
+    assert_eq!(owned, s);
+    assert_ne!(owned.as_ptr(), s.as_ptr());
+
+    let cow = Cow::Borrowed("hello");
+    let owned = BosToOwned::<str>::to_owned_target(&cow);
+    assert_eq!(owned, "hello");
+    assert_ne!(owned.as_ptr(), cow.as_ptr());
+}
+
+#[test]
+fn to_owned_target_on_wrappers() {
+    use borrow_or_share::{ByTarget, ForceBorrow, Utf8Bytes};
+    use core::pin::Pin;
+
+    // Wrappers owning a holder of the same target reuse its allocation.
+    let s = String::from("hello");
+    let ptr = s.as_ptr();
+    let owned = BosToOwned::<str>::into_owned_target(BosCell::new(s));
+    assert_eq!(owned.as_ptr(), ptr);
+    let owned = BosToOwned::<str>::into_owned_target(ByTarget::<_, str>::new(owned));
+    assert_eq!(owned.as_ptr(), ptr);
+    let owned = BosToOwned::<str>::into_owned_target(ForceBorrow(owned));
+    assert_eq!(owned.as_ptr(), ptr);
+
+    assert_eq!(BosToOwned::<str>::to_owned_target(&Pin::new(&*owned)), "hello");
+    let bytes = Utf8Bytes::try_new(owned.as_bytes()).unwrap();
+    assert_eq!(bytes.to_owned_target(), "hello");
+
+    let dyn_box: Box<dyn DynBos<str>> = Box::new(owned);
+    assert_eq!(dyn_box.into_owned_target(), "hello");
+}
+
+#[test]
+fn to_cow() {
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn to_cow(&'i self) -> Cow<'o, str> {
+            self.0.to_cow()
+        }
+    }
+
+    fn share<'a>(text: &Text<&'a str>) -> Cow<'a, str> {
+        text.to_cow()
+    }
+
+    let s = String::from("hello");
+    let cow = {
+        let text = Text(s.as_str());
+        share(&text)
+    };
+    assert!(matches!(cow, Cow::Borrowed(b) if std::ptr::eq(b, s.as_str())));
+
+    let text = Text(String::from("hello"));
+    assert!(matches!(text.to_cow(), Cow::Borrowed("hello")));
+}
+
+#[test]
+fn leak() {
+    fn leak<B: BorrowOrShareLeak<str>>(b: B) -> &'static str {
+        b.borrow_or_share_leak()
+    }
+
+    let s: &'static str = "hello";
+    assert!(std::ptr::eq(leak(s), s));
+    assert!(std::ptr::eq(leak(Cow::Borrowed(s)), s));
+
+    let owned = String::from("hello");
+    let ptr = owned.as_ptr();
+    let leaked: &'static str = leak(owned);
+    assert_eq!(leaked, "hello");
+    assert_eq!(leaked.as_ptr(), ptr);
+
+    let leaked: &'static str = leak(Cow::Owned(String::from("world")));
+    assert_eq!(leaked, "world");
+    assert_eq!(leak(LazyStr::from(String::from("lazy"))), "lazy");
+}
+
+#[test]
+fn contiguous() {
+    fn share<'a>(c: &Contiguous<'a, i32>) -> &'a [i32] {
+        slice(c)
+    }
+
+    let deque = VecDeque::from(vec![1, 2, 3]);
+    let shared = {
+        let c = Contiguous::new(&deque).unwrap();
+        share(&c)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+    assert!(std::ptr::eq(shared, deque.as_slices().0));
+}
+
+#[test]
+fn contiguous_wrapped() {
+    let mut deque = VecDeque::with_capacity(3);
+    deque.extend([2, 3]);
+    deque.push_front(1);
+    assert!(!deque.as_slices().1.is_empty());
+    assert!(Contiguous::new(&deque).is_none());
+
+    let c = Contiguous::make(&mut deque);
+    assert_eq!(slice(&c), [1, 2, 3]);
+    assert_eq!(c.try_borrow_or_share(), Some(&[1, 2, 3][..]));
+}
+
+#[test]
+fn cow_ref() {
+    fn share<'a>(cow: &&'a Cow<'_, str>) -> &'a str {
+        str(cow)
+    }
+
+    let borrowed = Cow::Borrowed("borrowed");
+    let owned: Cow<'_, str> = Cow::Owned(String::from("owned"));
+
+    let (b, o) = {
+        let (b, o) = (&borrowed, &owned);
+        (share(&b), share(&o))
+    };
+    assert_eq!(b, "borrowed");
+    assert_eq!(o, "owned");
+}
+
+#[test]
+fn free_function() {
+    use borrow_or_share::{borrow_or_share, Bos};
+
+    let s = String::from("hello");
+    assert_eq!(borrow_or_share::<str, _>(&s), "hello");
+    assert_eq!(<String as Bos<str>>::borrow_or_share(&s), "hello");
+    assert_eq!(<String as BorrowOrShare<'_, '_, str>>::borrow_or_share(&s), "hello");
+    assert_eq!(BorrowOrShare::<str>::borrow_or_share(&s), "hello");
+
+    let shared: &str = {
+        let r = &s;
+        borrow_or_share(&r)
+    };
+    assert_eq!(shared, "hello");
+}
+
+#[test]
+fn validate() {
+    use borrow_or_share::BorrowOrShareExt;
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn non_empty(&'i self) -> Option<&'o str> {
+            self.0.borrow_or_share_validate(|s: &str| !s.is_empty())
+        }
+    }
+
+    fn share<'a>(text: &Text<&'a str>) -> Option<&'a str> {
+        text.non_empty()
+    }
+
+    let s = String::from("hello");
+    let shared = {
+        let text = Text(s.as_str());
+        share(&text)
+    };
+    assert_eq!(shared, Some("hello"));
+    assert_eq!(share(&Text("")), None);
+
+    assert_eq!(Text(String::from("owned")).non_empty(), Some("owned"));
+    assert_eq!(Text(String::new()).non_empty(), None);
+}
+
+#[test]
+fn shares() {
+    use borrow_or_share::Shares;
+
+    fn register<'o, B: Shares<'o, str>>(registry: &mut Vec<&'o str>, b: B) {
+        registry.push(b.share());
+    }
+
+    fn register_slice<'o, B: Shares<'o, [i32]>>(registry: &mut Vec<&'o [i32]>, b: B) {
+        registry.push(b.share());
+    }
+
+    let s = String::from("hello");
+    let cow: Cow<'_, str> = Cow::Owned(String::from("cow"));
+    let mut strs = Vec::new();
+    register(&mut strs, s.as_str());
+    register(&mut strs, &s);
+    register(&mut strs, &cow);
+    register(&mut strs, BosCell::new(s.as_str()));
+    assert_eq!(strs, ["hello", "hello", "cow", "hello"]);
+
+    let v = vec![1, 2, 3];
+    let deque = VecDeque::from(vec![4, 5]);
+    let mut slices = Vec::new();
+    register_slice(&mut slices, &v);
+    register_slice(&mut slices, Contiguous::new(&deque).unwrap());
+    assert_eq!(slices, [&[1, 2, 3][..], &[4, 5]]);
+}
+
+#[test]
+fn bos_cell() {
+    let mut cell = BosCell::new(Cow::Owned(String::from("owned")));
+    assert_eq!(cell.as_ref::<str>(), "owned");
+
+    let s = String::from("borrowed");
+    let old = cell.replace(Cow::Borrowed(&s));
+    assert_eq!(old, "owned");
+    assert_eq!(cell.as_ref::<str>(), "borrowed");
+
+    cell.set(Cow::Owned(String::from("owned again")));
+    assert_eq!(cell.as_ref::<str>(), "owned again");
+
+    let mut cell = BosCell::new("first");
+    let first: &str = cell.as_ref();
+    cell.set("second");
+    assert_eq!(first, "first");
+    assert_eq!(cell.as_ref::<str>(), "second");
+}
+
+#[test]
+fn result() {
+    fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    let owned: Result<String, &str> = Ok(String::from("owned"));
+    let borrowed: Result<String, &str> = Err("borrowed");
+    assert_eq!(as_str(&owned), "owned");
+    assert_eq!(as_str(&borrowed), "borrowed");
+
+    fn borrows<B: BorrowsFrom<str>>(_: &B) {}
+    borrows(&borrowed);
+
+    assert_eq!(owned.into_owned_target(), "owned");
+    assert_eq!(borrowed.to_owned_target(), "borrowed");
+}
+
+#[test]
+fn stable() {
+    fn stable_ptr<B: StableBos<str>>(b: B) -> *const u8 {
+        let ptr = b.borrow_or_share().as_ptr();
+        let moved = Box::new(b);
+        assert_eq!((*moved).borrow_or_share().as_ptr(), ptr);
+        ptr
+    }
+
+    let s = String::from("hello");
+    let ptr = s.as_ptr();
+    assert_eq!(stable_ptr(s), ptr);
+
+    let rc = Rc::<str>::from("hello");
+    let ptr = rc.as_ptr();
+    assert_eq!(stable_ptr(rc), ptr);
+
+    fn assert_stable<T: ?Sized, B: StableBos<T>>(_: &B) {}
+    assert_stable::<[u8], _>(&vec![1u8]);
+    assert_stable::<[u8], _>(&Arc::new(vec![1u8]));
+    assert_stable::<str, _>(&"hello");
+    assert_stable::<str, _>(&&String::new());
+    assert_stable::<str, _>(&Box::<str>::from("hello"));
+    assert_stable::<str, _>(&Ok::<_, &str>(String::new()));
+    assert_stable::<[u8], _>(&Contiguous::new(&VecDeque::from(vec![1u8])).unwrap());
+}
+
+#[test]
+fn dyn_as_ref() {
+    fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    let owned = String::from("owned");
+    let shared = {
+        let dyn_ref: &dyn AsRef<str> = &owned;
+        as_str(&dyn_ref)
+    };
+    assert_eq!(shared, "owned");
+
+    let borrowed = "borrowed";
+    let dyn_ref: &dyn AsRef<str> = &borrowed;
+    assert_eq!(as_str(&dyn_ref), "borrowed");
+    assert_eq!(dyn_ref.to_owned_target(), "borrowed");
+}
+
+#[test]
+fn concat() {
+    use borrow_or_share::{borrow_or_share_concat, borrow_or_share_concat_str};
+
+    let owned = vec![1u8, 2];
+    let borrowed: &[u8] = &[3, 4];
+    assert_eq!(borrow_or_share_concat(&owned, &borrowed), [1, 2, 3, 4]);
+    assert_eq!(borrow_or_share_concat(&borrowed, &owned), [3, 4, 1, 2]);
+    assert!(borrow_or_share_concat::<u8, _, _>(&Vec::new(), &[]).is_empty());
+
+    let owned = String::from("foo");
+    assert_eq!(borrow_or_share_concat_str(&owned, &"bar"), "foobar");
+    assert_eq!(borrow_or_share_concat_str(&Cow::Borrowed("baz"), &owned), "bazfoo");
+}
+
+#[test]
+fn fmt() {
+    use borrow_or_share::{borrow_or_share_fmt, DisplayBos};
+    use core::fmt;
+
+    struct Text<T>(T);
+
+    impl<T: Bos<str>> fmt::Display for Text<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            borrow_or_share_fmt(&self.0, f)
+        }
+    }
+
+    impl<T: Bos<str>> fmt::Debug for Text<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Text(")?;
+            borrow_or_share_fmt(&self.0, f)?;
+            write!(f, ")")
+        }
+    }
+
+    let owned = Text(String::from("hello"));
+    let borrowed = Text("hello");
+    assert_eq!(owned.to_string(), borrowed.to_string());
+    assert_eq!(format!("{owned:?}"), "Text(hello)");
+    assert_eq!(format!("{owned:?}"), format!("{borrowed:?}"));
+    assert_eq!(format!("{:^9}", owned), format!("{:^9}", borrowed));
+
+    let owned = DisplayBos(String::from("world"));
+    let borrowed = DisplayBos::from("world");
+    assert_eq!(owned.to_string(), "world");
+    assert_eq!(format!("{owned:>6}"), format!("{borrowed:>6}"));
+    assert_eq!(DisplayBos(Cow::Borrowed("cow")).to_string(), "cow");
+    assert_eq!(borrowed.into_inner(), "world");
+}
+
+#[test]
+fn via_as_ref() {
+    use borrow_or_share::{bos_of, BorrowOrShareMut, ViaAsRef};
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    let s = ViaAsRef::from(String::from("hello"));
+    assert_eq!(str(&s), "hello");
+    assert_eq!(bytes(&s), b"hello");
+    assert_eq!(bos_of::<str, _>(&s), "hello");
+    assert_eq!(bos_of::<[u8], _>(&s).len(), 5);
+    assert_eq!(BosToOwned::<[u8]>::to_owned_target(&s), b"hello");
+
+    let mut v = ViaAsRef(vec![1u8, 2, 3]);
+    let slice: &mut [u8] = v.borrow_or_share_mut();
+    slice.reverse();
+    assert_eq!(v.into_inner(), [3, 2, 1]);
+}
+
+#[test]
+fn nested() {
+    use borrow_or_share::Nested;
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn share<'a>(nested: &Nested<&'a String, String>) -> &'a str {
+        nested.share()
+    }
+
+    let rc = Nested::<_, String>::new(Rc::new(String::from("counted")));
+    assert_eq!(str(&rc), "counted");
+
+    let arc = Nested::<_, Vec<u8>>::new(Arc::new(vec![1u8, 2]));
+    assert_eq!(bytes(&arc), [1, 2]);
+    assert_eq!(bytes(&arc.clone()), [1, 2]);
+
+    let s = String::from("shared");
+    let shared = {
+        let nested = Nested::<_, String>::new(&s);
+        assert_eq!(str(&nested), "shared");
+        share(&nested)
+    };
+    assert_eq!(shared, "shared");
+}
+
+#[test]
+fn project() {
+    use borrow_or_share::{Project, Shares};
+
+    struct User {
+        name: String,
+        email: Option<String>,
+    }
+
+    struct UserBoxed(Box<User>);
+
+    impl Bos<User> for UserBoxed {
+        type Ref<'this> = &'this User;
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            &this.0
+        }
+    }
+
+    fn name(user: &User) -> &str {
+        &user.name
+    }
+
+    fn share<'a>(p: &Project<&'a User, User, str>) -> &'a str {
+        str(p)
+    }
+
+    let boxed = UserBoxed(Box::new(User {
+        name: String::from("alice"),
+        email: None,
+    }));
+    let projected = Project::new(boxed, name);
+    assert_eq!(str(&projected), "alice");
+    assert_eq!(projected.get().0.email, None);
+
+    let user = User {
+        name: String::from("bob"),
+        email: Some(String::from("bob@example.com")),
+    };
+    let shared = {
+        let projected = Project::new(&user, name);
+        assert_eq!(projected.share(), "bob");
+        share(&projected)
+    };
+    assert_eq!(shared, "bob");
+
+    let email = Project::new(Some(&user), |u: &User| u.email.as_deref().unwrap_or(""));
+    assert_eq!(TryBorrowOrShare::<str>::try_borrow_or_share(&email), Some("bob@example.com"));
+}
+
+#[test]
+fn by_target() {
+    use borrow_or_share::ByTarget;
+    use std::{
+        cmp::Ordering,
+        collections::{BTreeSet, HashMap},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    type Key<B> = ByTarget<B, str>;
+
+    let owned = Key::new(String::from("b"));
+    let shared = Key::new("b");
+    let boxed: Key<Box<str>> = Key::from(Box::from("a"));
+
+    assert!(owned == shared);
+    assert!(shared == owned);
+    assert!(owned == "b");
+    assert!(boxed != owned);
+    assert_eq!(owned.cmp(&owned.clone()), Ordering::Equal);
+    assert_eq!(boxed.partial_cmp(&shared), Some(Ordering::Less));
+    assert!(owned > boxed && owned < "c");
+    assert_eq!(hash(&owned), hash(&shared));
+    assert_eq!(hash(&owned), hash("b"));
+    assert_eq!(format!("{owned:?}"), r#"ByTarget("b")"#);
+
+    let mut map = HashMap::new();
+    map.insert(owned.clone(), 1);
+    map.insert(Key::new(String::from("a")), 2);
+    assert_eq!(map.get("b"), Some(&1));
+    assert_eq!(map.get(shared.as_target()), Some(&1));
+    assert_eq!(map.get(boxed.as_target()), Some(&2));
+    assert_eq!(map.get("c"), None);
+    assert_eq!(map.insert(Key::new(String::from("b")), 3), Some(1));
+
+    let mut set = BTreeSet::new();
+    set.insert(Key::new(Cow::Borrowed("c")));
+    set.insert(Key::new(Cow::Owned(String::from("a"))));
+    set.insert(Key::new(Cow::Borrowed("b")));
+    assert!(!set.insert(Key::new(Cow::Borrowed("a"))));
+    assert!(set.contains("b"));
+    assert!(!set.contains("d"));
+    let order: Vec<&str> = set.iter().map(ByTarget::as_target).collect();
+    assert_eq!(order, ["a", "b", "c"]);
+
+    assert_eq!(owned.into_inner(), "b");
+}
+
+#[test]
+fn boo() {
+    use borrow_or_share::Boo;
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+
+    let borrowed = Boo::from("hello");
+    let owned = Boo::<str>::from(String::from("hello"));
+    assert_eq!(str(&borrowed), str(&owned));
+    assert!(borrowed.is_borrowed() && owned.is_owned());
+    assert_eq!(borrowed.shared(), Some("hello"));
+    assert_eq!(owned.shared(), None);
+    assert_eq!(format!("{borrowed:?}"), r#"Borrowed("hello")"#);
+    assert_eq!(format!("{owned:?}"), r#"Owned("hello")"#);
+    borrows::<str, _>(&borrowed);
+
+    let s = String::from("moved");
+    let ptr = s.as_ptr();
+    let moved = Boo::<str>::from(s).into_owned();
+    assert_eq!(moved.as_ptr(), ptr);
+    let cloned = borrowed.clone().into_owned();
+    assert_ne!(cloned.as_ptr(), borrowed.as_target().as_ptr());
+
+    let borrowed = Boo::<[u8]>::from(&[1u8, 2][..]);
+    let owned = Boo::<[u8]>::from(vec![1u8, 2]);
+    assert_eq!(bytes(&borrowed), bytes(&owned));
+    assert_eq!(bytes(&owned.clone()), [1, 2]);
+    assert_eq!(BosToOwned::<[u8]>::into_owned_target(owned), [1, 2]);
+
+    let cow: Cow<'_, str> = Boo::from("cow").into();
+    assert!(matches!(cow, Cow::Borrowed("cow")));
+    let cow: Cow<'_, str> = Boo::<str>::from(String::from("cow")).into();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert!(Boo::from(cow).is_owned());
+    assert!(Boo::from(Cow::Borrowed("cow")).is_borrowed());
+}
+
+#[test]
+fn projected() {
+    use borrow_or_share::{Projected, Shares};
+
+    fn project<'a>(text: &&'a String) -> Projected<'a, str> {
+        Projected::new(text)
+    }
+
+    let s = String::from("hello");
+    let projected = {
+        let r = &s;
+        project(&r)
+    };
+    assert_eq!(&*projected, "hello");
+    assert!(std::ptr::eq(projected.get(), s.as_str()));
+    assert!(std::ptr::eq(str(&projected), s.as_str()));
+    assert!(std::ptr::eq(projected.share(), s.as_str()));
+
+    let copy = projected;
+    assert_eq!(copy.len() + projected.len(), 10);
+    assert_eq!(Projected::from("literal").get(), "literal");
+}
+
+#[test]
+fn bos_substr_owned() {
+    use borrow_or_share::BosSubstr;
+
+    let token = BosSubstr::new(String::from("fn café()"), 3..8);
+    assert_eq!(str(&token), "café");
+    assert_eq!(BosToOwned::<str>::to_owned_target(&token.get().as_str()), "fn café()");
+    assert_eq!(token.into_inner(), "fn café()");
+}
+
+#[test]
+fn utf8_bytes_owned() {
+    use borrow_or_share::Utf8Bytes;
+
+    let utf8 = Utf8Bytes::try_new(b"owned".to_vec()).unwrap();
+    assert_eq!(str(&utf8), "owned");
+    assert_eq!(str(&Utf8Bytes::try_new(Cow::Borrowed(&b"cow"[..])).unwrap()), "cow");
+
+    let err = Utf8Bytes::try_new(vec![0xffu8]).unwrap_err();
+    assert_eq!(err.to_string(), "invalid utf-8 sequence of 1 bytes from index 0");
+    assert_eq!(err.into_inner(), [0xff]);
+}
+
+#[test]
+fn borrow_or_share_bytes() {
+    use borrow_or_share::BorrowOrShareExt;
+    use std::ffi::{CStr, CString};
+
+    fn share_str<'a>(s: &&'a str) -> &'a [u8] {
+        s.borrow_or_share_bytes()
+    }
+
+    fn share_c_str<'a>(s: &&'a CStr) -> &'a [u8] {
+        s.borrow_or_share_bytes()
+    }
+
+    let owned = String::from("héllo");
+    assert_eq!(owned.borrow_or_share_bytes(), "héllo".as_bytes());
+    let r = &owned;
+    assert_eq!(BorrowOrShareExt::borrow_or_share_bytes::<str>(&r), "héllo".as_bytes());
+
+    let shared = {
+        let s = owned.as_str();
+        share_str(&s)
+    };
+    assert!(std::ptr::eq(shared, owned.as_bytes()));
+
+    let c_owned = CString::new("bytes").unwrap();
+    assert_eq!(c_owned.borrow_or_share_bytes(), b"bytes");
+
+    let shared = {
+        let s = c_owned.as_c_str();
+        share_c_str(&s)
+    };
+    assert_eq!(shared, b"bytes");
+    assert_eq!(vec![1u8, 2].borrow_or_share_bytes(), [1, 2]);
+}
+
+#[test]
+fn manually_drop() {
+    fn borrows<B: BorrowsFrom<[i32]>>(_: &B) {}
+    fn shares<B: SharesWith<[i32]>>(_: &B) {}
+    fn share<'a>(md: &ManuallyDrop<&'a [i32]>) -> &'a [i32] {
+        slice(md)
+    }
+
+    let owned = ManuallyDrop::new(vec![1, 2, 3]);
+    assert_eq!(slice(&owned), [1, 2, 3]);
+    borrows(&owned);
+
+    let data = [4, 5];
+    let shared = {
+        let md = ManuallyDrop::new(&data[..]);
+        shares(&md);
+        share(&md)
+    };
+    assert_eq!(shared, [4, 5]);
+
+    assert_eq!(ManuallyDrop::new("hi").into_owned_target(), "hi");
+    drop(ManuallyDrop::into_inner(owned));
+}
+
+#[test]
+fn with_default() {
+    use borrow_or_share::WithDefault;
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn name(name: Option<String>) -> Text<WithDefault<String, str>> {
+        Text(WithDefault::new(name, "unnamed"))
+    }
+
+    let unnamed = name(None);
+    assert!(unnamed.0.is_default());
+    assert_eq!(unnamed.as_str(), "unnamed");
+
+    let named = name(Some(String::from("server")));
+    assert!(!named.0.is_default());
+    assert_eq!(named.as_str(), "server");
+    assert_eq!(named.0.into_inner().as_deref(), Some("server"));
+
+    let none = WithDefault::<&str, str>::none("default");
+    assert_eq!(none.into_owned_target(), "default");
+    assert_eq!(WithDefault::some("given", "default").into_owned_target(), "given");
+}
+
+#[test]
+fn annotated() {
+    use borrow_or_share::Annotated;
+
+    fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+    fn borrows<B: BorrowsFrom<str>>(_: &B) {}
+    fn shares<B: SharesWith<str>>(_: &B) {}
+
+    struct Span(usize, usize);
+
+    let mut owned = Annotated::new(Box::<str>::from("hello"), Span(0, 5));
+    borrows(&owned);
+    assert_eq!(as_str(&owned), "hello");
+    owned.meta.1 = 4;
+
+    let owned = owned.map_meta(|Span(start, end)| end - start);
+    let (holder, meta) = owned.as_parts();
+    assert_eq!((&**holder, *meta), ("hello", 4));
+
+    let data = String::from("world");
+    let shared = {
+        let annotated = Annotated::new(data.as_str(), Span(0, 5));
+        shares(&annotated);
+        as_str(&annotated)
+    };
+    assert_eq!(shared, "world");
+
+    let (holder, meta) = Annotated::new("x", 'm').map_meta(u32::from).into_parts();
+    assert_eq!((holder, meta), ("x", 109));
+}
+
+#[test]
+fn either_bos() {
+    use borrow_or_share::EitherBos;
+
+    struct Node<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Node<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    type Name = EitherBos<&'static str, String>;
+
+    let interned: Node<Name> = Node(EitherBos::Left("div"));
+    let runtime: Node<Name> = Node(EitherBos::Right(String::from("span")));
+    assert_eq!(interned.as_str(), "div");
+    assert_eq!(runtime.as_str(), "span");
+    assert!(interned.0.is_left() && runtime.0.is_right());
+
+    let upper = runtime.0.map_right(|s| s.to_uppercase());
+    assert_eq!(str(&upper), "SPAN");
+    let boxed = upper.map_left(Box::<str>::from);
+    assert_eq!(boxed.clone().into_owned_target(), "SPAN");
+
+    let res: Result<Box<str>, String> = boxed.into();
+    assert_eq!(res, Err(String::from("SPAN")));
+    assert_eq!(Name::from(Ok("p")), EitherBos::Left("p"));
+}
+
+#[test]
+fn hash() {
+    use borrow_or_share::{borrow_or_share_hash, HashBos};
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash_of<T: Hash + ?Sized>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    struct Key<T>(T);
+
+    impl<T: Bos<[u8]>> Hash for Key<T> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            borrow_or_share_hash::<[u8], _, _>(&self.0, state)
+        }
+    }
+
+    let data = vec![1u8, 2, 3];
+    assert_eq!(hash_of(&Key(data.clone())), hash_of(&Key(&data[..])));
+    assert_eq!(hash_of(&Key(Rc::<[u8]>::from(&data[..]))), hash_of(&data[..]));
+
+    let owned = HashBos::<_, str>::new(String::from("hello"));
+    let shared = HashBos::<_, str>::new("hello");
+    let boxed = HashBos::<_, str>::new(Box::<str>::from("hello"));
+    assert_eq!(hash_of(&owned), hash_of(&shared));
+    assert_eq!(hash_of(&owned), hash_of(&boxed));
+    assert_eq!(hash_of(&owned), hash_of("hello"));
+}
+
+#[test]
+fn vec_mut_ref() {
+    use borrow_or_share::BorrowOrShareMut;
+
+    struct Buf<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [i32]>> Buf<T> {
+        fn as_slice(&'i self) -> &'o [i32] {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn sum<'i, 'o, B: BorrowOrShare<'i, 'o, [i32]>>(b: &'i B) -> i32 {
+        b.borrow_or_share().iter().sum()
+    }
+
+    fn fill(v: &mut Vec<i32>) -> i32 {
+        v.extend([1, 2, 3]);
+        sum(&v)
+    }
+
+    fn share<'a>(buf: &Buf<&'a Vec<i32>>) -> &'a [i32] {
+        buf.as_slice()
+    }
+
+    fn borrows<B: BorrowsFrom<[i32]>>(_: &B) {}
+
+    let mut v = Vec::new();
+    assert_eq!(fill(&mut v), 6);
+
+    let mut buf = Buf(&mut v);
+    borrows(&buf.0);
+    <&mut Vec<i32> as BorrowOrShareMut<'_, '_, [i32]>>::borrow_or_share_mut(&mut buf.0)[0] = 4;
+    assert_eq!(buf.as_slice(), [4, 2, 3]);
+    assert_eq!(BosToOwned::<[i32]>::to_owned_target(&buf.0), [4, 2, 3]);
+
+    // Giving up the exclusive access yields a view that outlives the wrapper.
+    let view = {
+        let exclusive: &mut Vec<i32> = &mut v;
+        exclusive.push(5);
+        let buf = Buf(&*exclusive);
+        share(&buf)
+    };
+    assert_eq!(view, [4, 2, 3, 5]);
+}
+
+#[test]
+fn split_at() {
+    use borrow_or_share::BorrowOrShareExt;
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn split_at(&'i self, mid: usize) -> Option<(&'o str, &'o str)> {
+            self.0.borrow_or_share_split_at(mid)
+        }
+    }
+
+    fn share<'a>(text: &Text<&'a str>, mid: usize) -> Option<(&'a str, &'a str)> {
+        text.split_at(mid)
+    }
+
+    let s = String::from("日本語");
+    let shared = {
+        let text = Text(s.as_str());
+        share(&text, 3)
+    };
+    assert_eq!(shared, Some(("日", "本語")));
+    assert_eq!(share(&Text(&s), 0), Some(("", "日本語")));
+    assert_eq!(share(&Text(&s), 9), Some(("日本語", "")));
+
+    for mid in [1, 2, 4, 8, 10] {
+        assert_eq!(share(&Text(&s), mid), None);
+    }
+
+    let owned = Text(String::from("aé"));
+    assert_eq!(owned.split_at(1), Some(("a", "é")));
+    assert_eq!(owned.split_at(2), None);
+}
+
+struct Ptr<T: ?Sized>(Rc<T>);
+
+impl<T: ?Sized> core::ops::Deref for Ptr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+borrow_or_share::bos_for_deref_target!({T: ?Sized} Ptr<T>);
+
+#[test]
+fn custom_smart_pointer() {
+    use borrow_or_share::ViaDeref;
+
+    fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+
+    let ptr = Ptr(Rc::<str>::from("hello"));
+    borrows::<str, _>(&ptr);
+    assert_eq!(str(&ptr), "hello");
+
+    let ptr = Ptr(Rc::new(vec![1, 2]));
+    assert_eq!(slice(&*ptr), [1, 2]);
+
+    // A pointer from another crate is wrapped instead.
+    let wrapped = ViaDeref(Rc::new(String::from("world")));
+    assert_eq!(BorrowOrShare::<String>::borrow_or_share(&wrapped), "world");
+}
+
+#[test]
+fn string_mut_ref() {
+    use borrow_or_share::BorrowOrShareMut;
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn len<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> usize {
+        b.borrow_or_share().len()
+    }
+
+    fn append(s: &mut String) -> usize {
+        s.push_str("hello");
+        len(&s)
+    }
+
+    fn share<'a>(text: &Text<&'a String>) -> &'a str {
+        text.as_str()
+    }
+
+    fn borrows<B: BorrowsFrom<str>>(_: &B) {}
+
+    let mut s = String::new();
+    assert_eq!(append(&mut s), 5);
+
+    let mut text = Text(&mut s);
+    borrows(&text.0);
+    <&mut String as BorrowOrShareMut<'_, '_, str>>::borrow_or_share_mut(&mut text.0)
+        .make_ascii_uppercase();
+    assert_eq!(text.as_str(), "HELLO");
+    assert_eq!(BosToOwned::<str>::to_owned_target(&text.0), "HELLO");
+
+    // Giving up the exclusive access yields a view that outlives the wrapper.
+    let view = {
+        let exclusive: &mut String = &mut s;
+        exclusive.push('!');
+        let text = Text(&*exclusive);
+        share(&text)
+    };
+    assert_eq!(view, "HELLO!");
+}
+
+#[cfg(feature = "nightly")]
+mod cow_share {
+    use borrow_or_share::{Boo, CowShare};
+    use std::borrow::Cow;
+
+    // The shared reference outlives the holder.
+    fn share<'a, B: ?Sized, C: CowShare<'a, B>>(c: C) -> Option<&'a B> {
+        c.try_share()
+    }
+
+    #[test]
+    fn cow_borrowed() {
+        let s = String::from("hello");
+        let cow = Cow::Borrowed(s.as_str());
+        assert!(matches!(cow.borrow_or_share_cow(), Ok(r) if std::ptr::eq(r, s.as_str())));
+        assert_eq!(share(cow), Some("hello"));
+
+        let slice: Cow<'_, [i32]> = Cow::Borrowed(&[1, 2]);
+        assert_eq!(slice.borrow_or_share_cow(), Ok(&[1, 2][..]));
+    }
+
+    #[test]
+    fn cow_owned() {
+        let cow: Cow<'_, str> = Cow::Owned(String::from("hello"));
+        match cow.borrow_or_share_cow() {
+            Err(r) => assert!(std::ptr::eq(r, cow.as_ref())),
+            Ok(_) => panic!("owned `Cow` shared"),
+        }
+        assert_eq!(cow.try_share(), None);
+        assert_eq!(share::<str, _>(cow), None);
+
+        let slice: Cow<'_, [i32]> = Cow::Owned(vec![1, 2]);
+        assert_eq!(slice.borrow_or_share_cow(), Err(&[1, 2][..]));
+    }
+
+    #[test]
+    fn boo() {
+        let s = String::from("hello");
+        let borrowed = Boo::from(s.as_str());
+        assert!(matches!(borrowed.borrow_or_share_cow(), Ok(r) if std::ptr::eq(r, s.as_str())));
+        assert_eq!(share(borrowed), Some("hello"));
+
+        let owned = Boo::<str>::from(String::from("hello"));
+        assert_eq!(owned.borrow_or_share_cow(), Err("hello"));
+        assert_eq!(owned.try_share(), None);
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(all(feature = "bytemuck", feature = "alloc"))]
+
+use borrow_or_share::{BorrowOrShare, BorrowsFrom, PodBytes, SharesWith};
+
+fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+    b.borrow_or_share()
+}
+
+#[test]
+fn round_trip() {
+    fn borrows<B: BorrowsFrom<[u8]>>(_: &B) {}
+
+    let floats = PodBytes::<_, f32>::new(vec![1.5f32, -2.0, 0.25]);
+    borrows(&floats);
+    let data = bytes(&floats);
+    assert_eq!(data.len(), 12);
+    let back: Vec<f32> = data.chunks_exact(4).map(bytemuck::pod_read_unaligned).collect();
+    assert_eq!(back, [1.5, -2.0, 0.25]);
+
+    let words = PodBytes::<_, u32>::new([0xdead_beefu32, 7]);
+    let back: Vec<u32> = bytes(&words)
+        .chunks_exact(4)
+        .map(bytemuck::pod_read_unaligned)
+        .collect();
+    assert_eq!(back, [0xdead_beef, 7]);
+    assert_eq!(&bytes(&words)[..4], 0xdead_beefu32.to_ne_bytes());
+}
+
+#[test]
+fn share() {
+    fn shares<B: SharesWith<[u8]>>(_: &B) {}
+
+    fn share<'a>(b: &PodBytes<&'a [u32], u32>) -> &'a [u8] {
+        bytes(b)
+    }
+
+    let data = [1u32, 2];
+    let shared = {
+        let b = PodBytes::new(&data[..]);
+        shares(&b);
+        share(&b)
+    };
+    assert_eq!(shared, bytemuck::cast_slice::<u32, u8>(&data));
+}
@@ -0,0 +1,39 @@
+#![cfg(all(feature = "std", feature = "camino"))]
+
+use borrow_or_share::{BorrowOrShare, BosToOwned};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::{ffi::OsStr, path::Path};
+
+fn path<'i, 'o, B: BorrowOrShare<'i, 'o, Path> + ?Sized>(b: &'i B) -> &'o Path {
+    b.borrow_or_share()
+}
+
+#[test]
+fn utf8_path_buf() {
+    let buf = Utf8PathBuf::from("/tmp/file.txt");
+    assert_eq!(path(&buf), Path::new("/tmp/file.txt"));
+
+    let os: &OsStr = buf.borrow_or_share();
+    assert_eq!(os, "/tmp/file.txt");
+
+    let utf8: &Utf8Path = buf.borrow_or_share();
+    assert_eq!(utf8.extension(), Some("txt"));
+
+    let owned = BosToOwned::<Path>::into_owned_target(buf);
+    assert_eq!(owned, Path::new("/tmp/file.txt"));
+}
+
+#[test]
+fn utf8_path() {
+    fn share<'a>(p: &&'a Utf8Path) -> &'a Path {
+        path(p)
+    }
+
+    let buf = Utf8PathBuf::from("dir/file");
+    let shared = {
+        let p = buf.as_path();
+        share(&p)
+    };
+    assert_eq!(shared, Path::new("dir/file"));
+    assert_eq!(path(Utf8Path::new("dir")), Path::new("dir"));
+}
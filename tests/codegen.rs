@@ -0,0 +1,95 @@
+// Compiles `codegen/probe.rs` with optimizations and checks that going
+// through `borrow_or_share` generates the same code as a direct deref.
+// The assembly format is only parsed for x86-64 Linux.
+#![cfg(all(feature = "alloc", target_arch = "x86_64", target_os = "linux"))]
+
+use std::{collections::HashMap, env, fs, path::Path, process::Command};
+
+fn rustc(args: &[&str]) {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let status = Command::new(rustc).args(args).status().unwrap();
+    assert!(status.success(), "rustc failed");
+}
+
+/// Returns the instructions of each function in the assembly.
+///
+/// LLVM may merge identical functions, in which case one of them
+/// becomes an alias `name = other` (or `.set name, other` with older
+/// LLVM versions) and gets the body of the other.
+fn functions(asm: &str) -> HashMap<&str, Vec<&str>> {
+    let mut bodies = HashMap::new();
+    let mut aliases = Vec::new();
+    let mut current = None;
+
+    for line in asm.lines().map(str::trim) {
+        let alias = match line.strip_prefix(".set ") {
+            Some(set) => set.split_once(", "),
+            None => line.split_once(" = "),
+        };
+        if let Some((name, target)) = alias {
+            aliases.push((name, target));
+        } else if let Some(label) = line.strip_suffix(':') {
+            if label.starts_with(".Lfunc_end") {
+                current = None;
+            } else if !label.starts_with('.') {
+                current = Some(label);
+                bodies.insert(label, Vec::new());
+            }
+        } else if line.is_empty() || line.starts_with('.') || line.starts_with('#') {
+            continue;
+        } else if let Some(name) = current {
+            bodies.get_mut(name).unwrap().push(line);
+        }
+    }
+
+    for (name, target) in aliases {
+        let body = bodies[target].clone();
+        bodies.insert(name, body);
+    }
+    bodies
+}
+
+#[test]
+fn no_op() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    fs::create_dir_all(&out_dir).unwrap();
+    let out_dir = out_dir.to_str().unwrap();
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+
+    let lib = format!("{manifest_dir}/src/lib.rs");
+    rustc(&[
+        "--crate-name=borrow_or_share",
+        "--crate-type=rlib",
+        "--edition=2021",
+        "--cfg=feature=\"alloc\"",
+        "--cap-lints=allow",
+        "-O",
+        &lib,
+        "--out-dir",
+        out_dir,
+    ]);
+
+    let probe = format!("{manifest_dir}/tests/codegen/probe.rs");
+    let extern_lib = format!("borrow_or_share={out_dir}/libborrow_or_share.rlib");
+    let asm_path = format!("{out_dir}/probe.s");
+    rustc(&[
+        "--crate-type=lib",
+        "--edition=2021",
+        "--emit=asm",
+        "-O",
+        "--extern",
+        &extern_lib,
+        &probe,
+        "-o",
+        &asm_path,
+    ]);
+
+    let asm = fs::read_to_string(&asm_path).unwrap();
+    let functions = functions(&asm);
+    for name in ["string", "str", "vec", "cow"] {
+        let via_bos = &functions[&*format!("bos_{name}")];
+        let direct = &functions[&*format!("direct_{name}")];
+        assert!(!direct.is_empty(), "no code for `direct_{name}`");
+        assert_eq!(via_bos, direct, "`bos_{name}` differs from `direct_{name}`");
+    }
+}
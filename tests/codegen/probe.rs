@@ -0,0 +1,53 @@
+// Pairs of functions that should compile to the same code:
+// one goes through `borrow_or_share`, the other derefs directly.
+
+use borrow_or_share::BorrowOrShare;
+use std::borrow::Cow;
+
+#[no_mangle]
+#[inline(never)]
+pub fn bos_string(s: &String) -> &str {
+    s.borrow_or_share()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn direct_string(s: &String) -> &str {
+    s
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn bos_str<'a>(s: &&'a str) -> &'a str {
+    s.borrow_or_share()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn direct_str<'a>(s: &&'a str) -> &'a str {
+    s
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn bos_vec(v: &Vec<u32>) -> &[u32] {
+    v.borrow_or_share()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn direct_vec(v: &Vec<u32>) -> &[u32] {
+    v
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn bos_cow<'a>(s: &'a Cow<'_, str>) -> &'a str {
+    s.borrow_or_share()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn direct_cow<'a>(s: &'a Cow<'_, str>) -> &'a str {
+    s
+}
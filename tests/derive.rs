@@ -0,0 +1,100 @@
+#![cfg(all(feature = "derive", feature = "alloc"))]
+
+use borrow_or_share::{
+    BorrowOrShare, BorrowsFrom, Bos, Shares, SharesWith, StableBos, TryBorrowOrShare,
+};
+use std::rc::Rc;
+
+mod renamed {
+    pub use borrow_or_share as bos;
+}
+
+#[derive(Bos)]
+struct Name<T>(T);
+
+#[derive(Bos)]
+struct Named<T> {
+    inner: T,
+}
+
+#[derive(Bos)]
+#[bos(target = str)]
+#[bos(target = [u8])]
+struct Label<T: Clone>
+where
+    T: core::fmt::Debug,
+{
+    id: u32,
+    #[bos]
+    text: T,
+}
+
+#[derive(renamed::bos::Bos)]
+#[bos(crate = renamed::bos)]
+struct Renamed<T>(T);
+
+fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+    b.borrow_or_share()
+}
+
+fn as_bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+    b.borrow_or_share()
+}
+
+fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+fn shares<T: ?Sized, B: SharesWith<T>>(_: &B) {}
+fn stable<T: ?Sized, B: StableBos<T>>(_: &B) {}
+
+fn share_via<'a, B: Shares<'a, str>>(b: B) -> &'a str {
+    b.share()
+}
+
+#[test]
+fn newtype() {
+    let name = Name(String::from("alice"));
+    assert_eq!(as_str(&name), "alice");
+    borrows::<str, _>(&name);
+    stable::<str, _>(&name);
+
+    fn share<'a>(name: &Name<&'a str>) -> &'a str {
+        as_str(name)
+    }
+    let s = {
+        let name = Name("bob");
+        share(&name)
+    };
+    assert_eq!(s, "bob");
+    shares::<str, _>(&Name("bob"));
+    assert_eq!(share_via(Name("bob")), "bob");
+
+    let named = Named { inner: vec![1, 2] };
+    assert_eq!(named.borrow_or_share(), [1, 2]);
+    assert_eq!(named.try_borrow_or_share(), Some(&[1, 2][..]));
+}
+
+#[test]
+fn marked_field_and_targets() {
+    let label = Label {
+        id: 1,
+        text: Rc::<str>::from("main"),
+    };
+    assert_eq!(label.id, 1);
+    assert_eq!(as_str(&label), "main");
+    assert_eq!(as_bytes(&label), b"main");
+    stable::<[u8], _>(&label);
+
+    fn share<'a>(label: &Label<&'a str>) -> &'a str {
+        as_str(label)
+    }
+    let label = Label { id: 2, text: "lib" };
+    shares::<str, _>(&label);
+    assert_eq!(label.share(), "lib");
+    assert_eq!(share(&label), "lib");
+}
+
+#[test]
+fn crate_path() {
+    let name = Renamed(String::from("alice"));
+    assert_eq!(as_str(&name), "alice");
+    assert_eq!(share_via(Renamed("bob")), "bob");
+}
@@ -0,0 +1,7 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui-derive/*.rs");
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+use borrow_or_share::{BorrowOrShare, BorrowsFrom, Bos, SharesWith, TryBorrowOrShare};
+
+/// A tagged byte string, unsized like `str`.
+#[derive(Debug, PartialEq, Eq)]
+struct Tagged<D: ?Sized = [u8]> {
+    tag: u8,
+    data: D,
+}
+
+/// The owned counterpart of `Tagged`, like `String` to `str`.
+#[derive(Debug)]
+struct TaggedBuf(Box<Tagged>);
+
+impl TaggedBuf {
+    fn new<const N: usize>(tag: u8, data: [u8; N]) -> Self {
+        TaggedBuf(Box::new(Tagged { tag, data }))
+    }
+}
+
+impl Bos<Tagged> for TaggedBuf {
+    type Ref<'this> = &'this Tagged;
+
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        &this.0
+    }
+}
+
+impl BorrowsFrom<Tagged> for TaggedBuf {}
+
+struct Item<T>(T);
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, Tagged>> Item<T> {
+    fn tagged(&'i self) -> &'o Tagged {
+        self.0.borrow_or_share()
+    }
+
+    fn data(&'i self) -> &'o [u8] {
+        &self.tagged().data
+    }
+}
+
+#[test]
+fn owned() {
+    let item = Item(TaggedBuf::new(1, *b"owned"));
+    assert_eq!(item.tagged().tag, 1);
+    assert_eq!(item.data(), b"owned");
+}
+
+#[test]
+fn shared() {
+    fn share<'a>(item: &Item<&'a Tagged>) -> &'a [u8] {
+        item.data()
+    }
+
+    fn shares<B: SharesWith<Tagged>>(_: &B) {}
+
+    let buf = TaggedBuf::new(2, *b"shared");
+    let data = {
+        let item = Item(&*buf.0);
+        shares(&item.0);
+        share(&item)
+    };
+    assert_eq!(data, b"shared");
+
+    let unsized_ref: &Tagged = &Tagged { tag: 3, data: [0u8; 0] };
+    assert_eq!(Item(unsized_ref).data(), b"");
+}
+
+#[test]
+fn wrapped() {
+    fn tag<'i, 'o, B: TryBorrowOrShare<'i, 'o, Tagged>>(b: &'i B) -> Option<u8> {
+        b.try_borrow_or_share().map(|t| t.tag)
+    }
+
+    let boxed: Box<Tagged> = Box::new(Tagged { tag: 4, data: [1, 2] });
+    assert_eq!(Item(boxed).data(), [1, 2]);
+
+    let some = Some(TaggedBuf::new(5, []));
+    assert_eq!(tag(&some), Some(5));
+    assert_eq!(tag(&None::<TaggedBuf>), None);
+}
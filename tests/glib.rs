@@ -0,0 +1,42 @@
+#![cfg(feature = "glib")]
+
+use borrow_or_share::{BorrowOrShare, Bos};
+use glib::{GStr, GString};
+
+struct Label<T>(T);
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Label<T> {
+    fn text(&'i self) -> &'o str {
+        self.0.borrow_or_share()
+    }
+}
+
+impl<T: Bos<str>> AsRef<str> for Label<T> {
+    fn as_ref(&self) -> &str {
+        self.text()
+    }
+}
+
+#[test]
+fn gstring() {
+    let label = Label(GString::from("hello"));
+    assert_eq!(label.text(), "hello");
+    assert_eq!(label.as_ref(), Label(String::from("hello")).as_ref());
+
+    let s: &GStr = BorrowOrShare::borrow_or_share(&label.0);
+    assert_eq!(s, "hello");
+}
+
+#[test]
+fn gstr() {
+    fn share<'a>(label: &Label<&'a GStr>) -> &'a str {
+        label.text()
+    }
+
+    let s = GString::from("world");
+    let shared = {
+        let label = Label(s.as_gstr());
+        share(&label)
+    };
+    assert_eq!(shared, "world");
+}
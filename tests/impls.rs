@@ -0,0 +1,982 @@
+use borrow_or_share::{BorrowOrShare, BorrowOrShareMut, Bos, TryBorrowOrShare, ViaDeref};
+use core::cell::Cell;
+use core::convert::Infallible;
+use core::ops::{Deref, DerefMut};
+
+fn cells<'i, 'o, B: BorrowOrShare<'i, 'o, [Cell<u8>]> + ?Sized>(b: &'i B) -> &'o [Cell<u8>] {
+    b.borrow_or_share()
+}
+
+#[test]
+fn cell_array() {
+    let cell = Cell::new([0u8; 8]);
+    let slice = cells(&cell);
+    assert_eq!(slice.len(), 8);
+
+    slice[3].set(42);
+    assert_eq!(cell.get()[3], 42);
+}
+
+#[test]
+fn cell_slice() {
+    let cell = Cell::new([1u8, 2, 3]);
+    let cell: &Cell<[u8]> = &cell;
+    let slice = <Cell<[u8]> as Bos<[Cell<u8>]>>::borrow_or_share(cell);
+
+    slice[0].set(slice[2].get());
+    assert_eq!(slice.iter().map(Cell::get).sum::<u8>(), 8);
+}
+
+#[test]
+fn atomic_slice() {
+    use borrow_or_share::SharesWith;
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    fn atomics<'i, 'o, B: BorrowOrShare<'i, 'o, [AtomicU8]>>(b: &'i B) -> &'o [AtomicU8] {
+        b.borrow_or_share()
+    }
+
+    fn shares<B: SharesWith<[AtomicU8]>>(_: &B) {}
+
+    fn share<'a>(b: &&'a [AtomicU8]) -> &'a [AtomicU8] {
+        atomics(b)
+    }
+
+    let buf = [AtomicU8::new(1), AtomicU8::new(2)];
+    let shared = {
+        let slice: &[AtomicU8] = &buf;
+        shares(&slice);
+        share(&slice)
+    };
+    shared[0].fetch_add(10, Ordering::Relaxed);
+    assert_eq!(buf[0].load(Ordering::Relaxed), 11);
+    assert_eq!(atomics(&&buf[1..])[0].load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn ptr_eq() {
+    let data = [1u8, 2, 3];
+
+    let (a, b) = (&data[..], &data[..]);
+    assert!(borrow_or_share::borrow_or_share_ptr_eq::<[u8], _, _>(&a, &b));
+
+    let (a, b) = (data, data);
+    assert!(!borrow_or_share::borrow_or_share_ptr_eq::<[u8], _, _>(&a, &b));
+}
+
+struct Name<T>(T);
+
+impl<'i, 'o, T: TryBorrowOrShare<'i, 'o, [u8]>> Name<T> {
+    fn get(&'i self) -> Option<&'o [u8]> {
+        self.0.try_borrow_or_share()
+    }
+}
+
+#[test]
+fn try_option() {
+    fn share<'a>(name: &Name<Option<&'a [u8]>>) -> Option<&'a [u8]> {
+        name.get()
+    }
+
+    let data = [1u8, 2];
+    let shared = {
+        let name = Name(Some(&data[..]));
+        share(&name)
+    };
+    assert_eq!(shared, Some(&data[..]));
+
+    assert_eq!(Name(None::<[u8; 2]>).get(), None);
+    assert_eq!(Name(Some([3u8])).get(), Some(&[3][..]));
+    assert_eq!(Name([4u8]).get(), Some(&[4][..]));
+}
+
+#[test]
+#[cfg(bos_once_cell)]
+fn try_once_cell() {
+    use core::cell::OnceCell;
+
+    let name = Name(OnceCell::<[u8; 1]>::new());
+    assert_eq!(name.get(), None);
+
+    name.0.set([1]).unwrap();
+    assert_eq!(name.get(), Some(&[1][..]));
+}
+
+struct Guard<'a>(&'a mut [u8]);
+
+impl Deref for Guard<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl DerefMut for Guard<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0
+    }
+}
+
+#[test]
+fn via_deref() {
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn bytes_mut<'i, 'o, B: BorrowOrShareMut<'i, 'o, [u8]>>(b: &'i mut B) -> &'o mut [u8] {
+        b.borrow_or_share_mut()
+    }
+
+    let mut buf = [1, 2, 3];
+    let mut guard = ViaDeref(Guard(&mut buf));
+    assert_eq!(bytes(&guard), [1, 2, 3]);
+
+    bytes_mut(&mut guard)[0] = 42;
+    assert_eq!(**guard, [42, 2, 3]);
+    assert_eq!(guard.into_inner().0, [42, 2, 3]);
+
+    let cell: &core::cell::RefCell<[u8]> = &core::cell::RefCell::new([4, 5]);
+    let mut guard = ViaDeref::from(cell.borrow_mut());
+    bytes_mut(&mut guard)[1] = 6;
+    assert_eq!(bytes(&guard), [4, 6]);
+    drop(guard);
+    assert_eq!(bytes(&ViaDeref(cell.borrow())), [4, 6]);
+}
+
+#[test]
+fn infallible() {
+    enum Owner<'a> {
+        Real(&'a str),
+        Never(Infallible),
+    }
+
+    impl<'a> Bos<str> for Owner<'a> {
+        type Ref<'this> = &'this str where Self: 'this;
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            match this {
+                Owner::Real(s) => s,
+                Owner::Never(never) => never.borrow_or_share(),
+            }
+        }
+    }
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    assert_eq!(Text(Owner::Real("real")).as_str(), "real");
+    let _: fn(Infallible) -> Owner<'static> = Owner::Never;
+
+    let text: Option<Text<Infallible>> = None;
+    assert!(text.map(|t| t.as_str().len()).is_none());
+}
+
+struct Stack<const N: usize>([u8; N]);
+
+impl<const N: usize> Deref for Stack<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+borrow_or_share::bos_for_deref_target!({const N: usize} Stack<N>);
+
+#[test]
+fn deref_target_macro() {
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    let stack = Stack([1, 2, 3]);
+    assert_eq!(bytes(&stack), [1, 2, 3]);
+    assert_eq!(stack.try_borrow_or_share(), Some(&[1, 2, 3][..]));
+}
+
+#[test]
+fn free_functions() {
+    use borrow_or_share::{borrow_or_share, bos_of};
+
+    let buf = [1u8, 2, 3];
+    let cells = Cell::new([1u8, 2, 3]);
+
+    assert_eq!(bos_of::<[u8], _>(&buf), [1, 2, 3]);
+    assert_eq!(bos_of::<[Cell<u8>], _>(&cells).len(), 3);
+
+    let shared: &[u8] = {
+        let r = &buf[..];
+        borrow_or_share::<[u8], _>(&r)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+}
+
+#[test]
+fn filled() {
+    use borrow_or_share::Filled;
+
+    fn share<'a>(f: &Filled<'a, u8, 8>) -> &'a [u8] {
+        f.borrow_or_share()
+    }
+
+    let buf = [1, 2, 3, 0, 0, 0, 0, 0];
+    let prefix = {
+        let filled = Filled::new(&buf, 3);
+        assert_eq!(filled.len(), 3);
+        share(&filled)
+    };
+    assert_eq!(prefix, [1, 2, 3]);
+    assert!(Filled::new(&buf, 0).borrow_or_share().is_empty());
+    assert_eq!(Filled::new(&buf, 8).borrow_or_share().len(), 8);
+}
+
+#[test]
+#[should_panic = "filled length 9 exceeds capacity 8"]
+fn filled_too_long() {
+    borrow_or_share::Filled::new(&[0u8; 8], 9);
+}
+
+#[test]
+fn bos_slice() {
+    use borrow_or_share::{BosSlice, Shares, SharesWith};
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn shares<B: SharesWith<[u8]>>(_: &B) {}
+
+    fn share<'a>(slice: &BosSlice<&'a [u8]>) -> &'a [u8] {
+        bytes(slice)
+    }
+
+    let buf = [0u8, 1, 2, 3, 4, 5];
+    let shared = {
+        let slice = BosSlice::new(&buf[..], 1..5);
+        shares(&slice);
+        assert_eq!(slice.range(), 1..5);
+        share(&slice)
+    };
+    assert_eq!(shared, [1, 2, 3, 4]);
+
+    let narrowed = {
+        let slice = BosSlice::new(&buf[..], 1..5).narrow(1..3);
+        assert_eq!(slice.range(), 2..4);
+        Shares::share(&slice)
+    };
+    assert_eq!(narrowed, [2, 3]);
+
+    let owned = BosSlice::new(buf, 4..6);
+    assert_eq!(bytes(&owned), [4, 5]);
+    assert!(bytes(&owned.clone().narrow(2..2)).is_empty());
+    assert_eq!(owned.into_inner(), buf);
+
+    assert!(BosSlice::try_new(&buf[..], 0..6).is_some());
+    assert!(BosSlice::try_new(&buf[..], 3..7).is_none());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = BosSlice::try_new(&buf[..], 4..3);
+    assert!(reversed.is_none());
+}
+
+#[test]
+#[should_panic = "range end index 7 out of range for slice of length 6"]
+fn bos_slice_out_of_range() {
+    borrow_or_share::BosSlice::new([0u8; 6], 2..7);
+}
+
+#[test]
+#[should_panic = "range end index 3 out of range for slice of length 2"]
+fn bos_slice_narrow_out_of_range() {
+    borrow_or_share::BosSlice::new(&[0u8; 6][..], 1..3).narrow(1..3);
+}
+
+#[test]
+fn array_ref() {
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn text<'i, 'o, B: TryBorrowOrShare<'i, 'o, str>>(b: &'i B) -> Option<&'o str> {
+        b.try_borrow_or_share()
+    }
+
+    let valid = *b"hello";
+    let (raw, s) = {
+        let r = &valid;
+        (bytes(&r), text(&r))
+    };
+    assert_eq!(raw, b"hello");
+    assert_eq!(s, Some("hello"));
+
+    let invalid = [0xffu8, 0xfe];
+    let r = &invalid;
+    assert_eq!(text(&r), None);
+    let err = core::str::from_utf8(bytes(&r)).unwrap_err();
+    assert_eq!(err.valid_up_to(), 0);
+}
+
+#[test]
+fn array_lengths() {
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn bytes_mut<'i, 'o, B: BorrowOrShareMut<'i, 'o, [u8]>>(b: &'i mut B) -> &'o mut [u8] {
+        b.borrow_or_share_mut()
+    }
+
+    fn text<'i, 'o, B: TryBorrowOrShare<'i, 'o, str>>(b: &'i B) -> Option<&'o str> {
+        b.try_borrow_or_share()
+    }
+
+    fn check<const N: usize>(mut array: [u8; N]) {
+        assert_eq!(bytes(&array).len(), N);
+        assert_eq!(bytes(&&array).len(), N);
+        assert_eq!(bytes_mut(&mut array).len(), N);
+        assert_eq!(bytes(&array).as_ptr(), array.as_ptr());
+    }
+
+    check([]);
+    check([1]);
+    check([2; 4096]);
+
+    let empty: [u8; 0] = [];
+    assert!(bytes(&empty).is_empty());
+    assert_eq!(text(&&empty), Some(""));
+
+    static LARGE: [u8; 65536] = [b'a'; 65536];
+    let shared: &'static [u8] = bytes(&&LARGE);
+    assert_eq!(shared.len(), 65536);
+    assert!(shared.iter().all(|&b| b == b'a'));
+    assert_eq!(text(&&LARGE).map(str::len), Some(65536));
+}
+
+#[test]
+fn borrow_or_share_as() {
+    use borrow_or_share::prelude::*;
+
+    struct Bytes([u8; 3]);
+
+    impl Bos<[u8; 3]> for Bytes {
+        type Ref<'this> = &'this [u8; 3];
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            &this.0
+        }
+    }
+
+    impl Bos<[u8]> for Bytes {
+        type Ref<'this> = &'this [u8];
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            &this.0
+        }
+    }
+
+    let bytes = Bytes([1, 2, 3]);
+    assert_eq!(bytes.borrow_or_share_as::<[u8; 3]>(), &[1, 2, 3]);
+    assert_eq!(bytes.borrow_or_share_as::<[u8]>().len(), 3);
+
+    let buf = [4, 5, 6];
+    let r = &buf;
+    let shared: &[u8] = (&r).borrow_or_share_as::<[u8]>();
+    assert_eq!(shared, [4, 5, 6]);
+}
+
+#[test]
+fn ref_cell_ref() {
+    use borrow_or_share::RefCellRef;
+    use core::cell::RefCell;
+
+    fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, Vec<u8>>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    let cell = RefCell::new(vec![1u8, 2, 3]);
+    let guard = RefCellRef::borrow(&cell);
+    assert_eq!(slice(&guard), [1, 2, 3]);
+    assert!(cell.try_borrow_mut().is_err());
+    drop(guard);
+
+    cell.borrow_mut().push(4);
+    let guard = RefCellRef::from(cell.borrow());
+    assert_eq!(slice(&guard), [1, 2, 3, 4]);
+    assert_eq!(guard.len(), 4);
+}
+
+#[test]
+fn target_hint() {
+    use borrow_or_share::{borrow_or_share_with, BorrowOrShareExt, Target};
+
+    macro_rules! len {
+        ($b:expr, $t:ty) => {
+            borrow_or_share_with($b, Target::<$t>::new()).len()
+        };
+    }
+
+    let buf = [1u8, 2, 3];
+    let r = &buf;
+    assert_eq!(len!(&r, [u8]), 3);
+    assert_eq!(len!(&r, [u8; 3]), 3);
+
+    const SLICE: Target<[u8]> = Target::new();
+    let shared: &[u8] = {
+        let r = &buf;
+        (&r).bos_with(SLICE)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+    assert_eq!(format!("{:?}", SLICE), "Target<[u8]>");
+}
+
+#[test]
+fn via_borrow() {
+    use borrow_or_share::ViaBorrow;
+    use core::borrow::{Borrow, BorrowMut};
+
+    struct Name([u8; 4]);
+
+    impl Borrow<[u8]> for Name {
+        fn borrow(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl BorrowMut<[u8]> for Name {
+        fn borrow_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [u8]>> Text<T> {
+        fn as_bytes(&'i self) -> &'o [u8] {
+            self.0.borrow_or_share()
+        }
+    }
+
+    impl<'i, 'o, T: BorrowOrShareMut<'i, 'o, [u8]>> Text<T> {
+        fn as_bytes_mut(&'i mut self) -> &'o mut [u8] {
+            self.0.borrow_or_share_mut()
+        }
+    }
+
+    let mut text = Text(ViaBorrow::from(Name(*b"name")));
+    assert_eq!(text.as_bytes(), b"name");
+    text.as_bytes_mut().make_ascii_uppercase();
+    assert_eq!(text.0.into_inner().0, *b"NAME");
+}
+
+#[test]
+fn force_borrow() {
+    use borrow_or_share::{BorrowsFrom, ForceBorrow};
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn try_bytes<'i, 'o, B: TryBorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> Option<&'o [u8]> {
+        b.try_borrow_or_share()
+    }
+
+    fn borrows<B: BorrowsFrom<[u8]>>(_: &B) {}
+
+    let buf = [1u8, 2, 3];
+    let forced = ForceBorrow::from(&buf);
+    borrows(&forced);
+    assert_eq!(bytes(&forced), [1, 2, 3]);
+    assert_eq!(try_bytes(&ForceBorrow(Some(buf))), Some(&buf[..]));
+    assert_eq!(try_bytes(&ForceBorrow(None::<[u8; 0]>)), None);
+    assert_eq!(forced.into_inner(), &buf);
+}
+
+#[test]
+fn from_fn() {
+    use borrow_or_share::{from_fn, FromFn};
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    let buf = [1u8, 2, 3];
+    let mut iter = buf.iter();
+    iter.next();
+
+    let rest = from_fn(iter, |it| it.as_slice());
+    assert_eq!(bytes(&rest), [2, 3]);
+    assert_eq!(bytes(&rest.clone()), [2, 3]);
+
+    let split: FromFn<_, [u8]> = from_fn(buf.split_at(1), |s| s.1);
+    assert_eq!(bytes(&split), [2, 3]);
+    assert_eq!(split.into_inner().0, [1]);
+}
+
+#[test]
+fn pin() {
+    use core::pin::Pin;
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn share<'a>(pin: &Pin<&'a [u8]>) -> &'a [u8] {
+        bytes(pin)
+    }
+
+    let buf = [1u8, 2, 3];
+    let shared = {
+        let pin = Pin::new(&buf[..]);
+        share(&pin)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+
+    let mut buf = [4u8, 5];
+    let mut pin = Pin::new(&mut buf[..]);
+    assert_eq!(bytes(&pin), [4, 5]);
+    BorrowOrShareMut::<[u8]>::borrow_or_share_mut(&mut pin).reverse();
+    assert_eq!(buf, [5, 4]);
+}
+
+#[test]
+fn borrow_or_share_copied() {
+    use borrow_or_share::BorrowOrShareExt;
+
+    struct Port(u32);
+
+    impl Bos<u32> for Port {
+        type Ref<'this> = &'this u32;
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            &this.0
+        }
+    }
+
+    fn port<B: Bos<u32>>(b: &B) -> u32 {
+        b.borrow_or_share_copied()
+    }
+
+    let n = 8080;
+    let r = &n;
+    assert_eq!(Port(n).borrow_or_share_copied::<u32>(), 8080);
+    assert_eq!(BorrowOrShareExt::borrow_or_share_copied::<u32>(&r), 8080);
+    assert_eq!(port(&Port(443)), 443);
+    assert_eq!(port(&r), 8080);
+}
+
+#[test]
+fn display_via() {
+    use borrow_or_share::DisplayVia;
+    use core::ffi::CStr;
+
+    let s = DisplayVia::<_, str>::new("tab\there");
+    assert_eq!(format!("{s}"), "tab\there");
+    assert_eq!(format!("{s:?}"), r#""tab\there""#);
+    assert_eq!(format!("[{s:>10}]"), "[  tab\there]");
+
+    let bytes = DisplayVia::<_, [u8]>::new([0x00, 0x1f, 0xab]);
+    assert_eq!(format!("{bytes:?}"), "001fab");
+    assert_eq!(format!("{bytes:x}"), "001fab");
+    assert_eq!(format!("{bytes:X}"), "001FAB");
+    assert_eq!(format!("{:?}", DisplayVia::<_, [u8]>::new(&[][..])), "");
+
+    let c = CStr::from_bytes_with_nul(b"caf\xc3\xa9 \xff!\0").unwrap();
+    let c = DisplayVia::<_, CStr>::from(c);
+    assert_eq!(format!("{c}"), "café \u{FFFD}!");
+    assert_eq!(format!("{c:?}"), format!("{:?}", c.as_target()));
+
+    let c = CStr::from_bytes_with_nul(b"a\tb\0").unwrap();
+    assert_eq!(format!("{:?}", DisplayVia::<_, CStr>::new(c)), r#""a\tb""#);
+
+    let truncated = CStr::from_bytes_with_nul(b"ab\xe2\x82\0").unwrap();
+    assert_eq!(format!("{}", DisplayVia::<_, CStr>::new(truncated)), "ab\u{FFFD}");
+}
+
+#[test]
+fn bos_substr() {
+    use borrow_or_share::{BosSubstr, SharesWith, SubstrError};
+
+    fn str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    fn shares<B: SharesWith<str>>(_: &B) {}
+
+    fn share<'a>(substr: &BosSubstr<&'a str>) -> &'a str {
+        str(substr)
+    }
+
+    // "é" and "ü" take two bytes, "€" three.
+    let source = "é€ü!";
+    let shared = {
+        let substr = BosSubstr::new(source, 2..7);
+        shares(&substr);
+        assert_eq!(substr.as_full(), source);
+        share(&substr)
+    };
+    assert_eq!(shared, "€ü");
+
+    assert_eq!(BosSubstr::try_new(source, 0..1), Err(SubstrError::NotCharBoundary));
+    assert_eq!(BosSubstr::try_new(source, 3..5), Err(SubstrError::NotCharBoundary));
+    assert_eq!(BosSubstr::try_new(source, 0..9), Err(SubstrError::OutOfBounds));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = BosSubstr::try_new(source, 5..2);
+    assert_eq!(reversed, Err(SubstrError::OutOfBounds));
+    assert_eq!(str(&BosSubstr::new(source, 0..8)), source);
+    assert_eq!(str(&BosSubstr::new(source, 8..8)), "");
+
+    let substr = BosSubstr::new(source, 2..8);
+    assert_eq!(substr.clone().slice(0..5).map(|s| s.range()), Ok(2..7));
+    assert_eq!(substr.clone().slice(1..4), Err(SubstrError::NotCharBoundary));
+    assert_eq!(substr.clone().slice(0..7), Err(SubstrError::OutOfBounds));
+
+    let narrowed = substr.slice(3..5).unwrap();
+    assert_eq!(narrowed.range(), 5..7);
+    assert_eq!(share(&narrowed), "ü");
+}
+
+#[test]
+#[should_panic = "invalid substring: byte range not on char boundaries"]
+fn bos_substr_not_char_boundary() {
+    borrow_or_share::BosSubstr::new("é", 0..1);
+}
+
+#[test]
+fn utf8_bytes() {
+    use borrow_or_share::{SharesWith, Utf8Bytes};
+
+    fn str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    fn shares<B: SharesWith<str>>(_: &B) {}
+
+    fn share<'a>(utf8: &Utf8Bytes<&'a [u8]>) -> &'a str {
+        str(utf8)
+    }
+
+    let bytes = "héllo".as_bytes();
+    let shared = {
+        let utf8 = Utf8Bytes::try_new(bytes).unwrap();
+        shares(&utf8);
+        assert_eq!(utf8.as_str(), "héllo");
+        share(&utf8)
+    };
+    assert_eq!(shared, "héllo");
+
+    let array = Utf8Bytes::try_new(*b"abc").unwrap();
+    assert_eq!(str(&array), "abc");
+    assert_eq!(array.into_inner(), *b"abc");
+
+    let err = Utf8Bytes::try_new(&b"ab\xc3"[..]).unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 2);
+    assert_eq!(err.utf8_error().error_len(), None);
+    assert_eq!(err.into_inner(), b"ab\xc3");
+}
+
+// A holder whose bytes turn invalid after the first borrow,
+// which is only possible with interior mutability.
+struct Flaky {
+    calls: Cell<u32>,
+}
+
+impl Bos<[u8]> for Flaky {
+    type Ref<'this> = &'this [u8];
+
+    fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+        let calls = this.calls.get();
+        this.calls.set(calls + 1);
+        if calls == 0 {
+            b"valid"
+        } else {
+            b"\xff"
+        }
+    }
+}
+
+#[test]
+#[should_panic = "bytes no longer valid UTF-8"]
+fn utf8_bytes_changed() {
+    let utf8 = borrow_or_share::Utf8Bytes::try_new(Flaky { calls: Cell::new(0) }).ok();
+    let utf8 = utf8.expect("valid on construction");
+    <borrow_or_share::Utf8Bytes<Flaky> as BorrowOrShare<'_, '_, str>>::borrow_or_share(&utf8);
+}
+
+#[test]
+fn array_mut_ref() {
+    use borrow_or_share::BorrowsFrom;
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+        b.borrow_or_share()
+    }
+
+    fn borrows<B: BorrowsFrom<[u8]>>(_: &B) {}
+
+    struct Buf<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [u8]>> Buf<T> {
+        fn as_slice(&'i self) -> &'o [u8] {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn share<'a>(buf: &Buf<&'a [u8; 32]>) -> &'a [u8] {
+        buf.as_slice()
+    }
+
+    let mut storage = [0u8; 32];
+    let mut buf = Buf(&mut storage);
+    borrows(&buf.0);
+    let slice = <&mut [u8; 32] as BorrowOrShareMut<'_, '_, [u8]>>::borrow_or_share_mut(&mut buf.0);
+    slice[..3].copy_from_slice(&[1, 2, 3]);
+    assert_eq!(bytes(&buf.0)[..4], [1, 2, 3, 0]);
+    assert_eq!(buf.as_slice().len(), 32);
+
+    // Giving up the exclusive access yields a view that outlives the wrapper.
+    let view = {
+        let exclusive: &mut [u8; 32] = &mut storage;
+        exclusive[31] = 9;
+        let buf = Buf(&*exclusive);
+        share(&buf)
+    };
+    assert_eq!(view[..3], [1, 2, 3]);
+    assert_eq!(view[31], 9);
+}
+
+#[test]
+fn validated() {
+    use borrow_or_share::{AsciiOnly, BorrowsFrom, Shares, SharesWith, Validated};
+
+    fn str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    fn shares<B: SharesWith<str>>(_: &B) {}
+    fn borrows<B: BorrowsFrom<str>>(_: &B) {}
+
+    fn share<'a>(v: &Validated<&'a str, AsciiOnly>) -> &'a str {
+        str(v)
+    }
+
+    let source = "ascii";
+    let shared = {
+        let v = Validated::<_, AsciiOnly>::try_new(source).unwrap();
+        shares(&v);
+        assert_eq!(format!("{v:?}"), r#"Validated("ascii")"#);
+        share(&v)
+    };
+    assert!(core::ptr::eq(shared, source));
+
+    let v = Validated::<_, AsciiOnly>::try_new(source).unwrap();
+    let shared: &str = Shares::share(&v.clone());
+    assert_eq!(shared, "ascii");
+    assert_eq!(v.into_inner(), source);
+
+    let err = Validated::<_, AsciiOnly>::try_new("abc√").unwrap_err();
+    assert_eq!(err.valid_up_to(), 3);
+    assert_eq!(err.to_string(), "non-ASCII character at index 3");
+    assert!(Validated::<_, AsciiOnly>::try_new("").is_ok());
+
+    struct Owned([u8; 2]);
+
+    impl Bos<str> for Owned {
+        type Ref<'this> = &'this str;
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            core::str::from_utf8(&this.0).unwrap()
+        }
+    }
+
+    impl BorrowsFrom<str> for Owned {}
+
+    let v = Validated::<_, AsciiOnly>::try_new(Owned(*b"ok")).unwrap();
+    borrows(&v);
+    assert_eq!(str(&v), "ok");
+    assert!(Validated::<_, AsciiOnly>::try_new(Owned(*b"\xc3\xa9")).is_err());
+}
+
+#[test]
+#[cfg(bos_once_cell)]
+fn lazy_bos() {
+    use borrow_or_share::LazyBos;
+
+    fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8; 3]>>(b: &'i B) -> &'o [u8; 3] {
+        b.borrow_or_share()
+    }
+
+    let calls = Cell::new(0);
+    let lazy = LazyBos::new(|| {
+        calls.set(calls.get() + 1);
+        [1u8, 2, 3]
+    });
+    assert_eq!(calls.get(), 0);
+    assert_eq!(format!("{lazy:?}"), "LazyBos(OnceCell(<uninit>))");
+
+    for _ in 0..3 {
+        assert_eq!(bytes(&lazy), &[1, 2, 3]);
+    }
+    assert_eq!(calls.get(), 1);
+    assert!(core::ptr::eq(bytes(&lazy), LazyBos::get(&lazy).unwrap()));
+
+    let default = LazyBos::<[u8; 3]>::default();
+    assert_eq!(LazyBos::get(&default), None);
+    assert_eq!(bytes(&default), &[0; 3]);
+}
+
+#[test]
+fn literal_macros() {
+    use borrow_or_share::{bos_bytes, bos_str, Shares, SharesWith};
+
+    fn shares<T: ?Sized, B: SharesWith<T>>(_: &B) {}
+    fn share_str<B: Shares<'static, str>>(b: B) -> &'static str {
+        b.share()
+    }
+    fn share_bytes<B: Shares<'static, [u8]>>(b: B) -> &'static [u8] {
+        b.share()
+    }
+
+    let s = bos_str!("hello");
+    shares::<str, _>(&s);
+    assert_eq!(share_str(s), "hello");
+
+    let b = bos_bytes!(b"hello");
+    shares::<[u8], _>(&b);
+    assert_eq!(share_bytes(b), b"hello");
+    assert_eq!(share_bytes(bos_bytes!(&[])), []);
+}
+
+#[test]
+fn shared() {
+    use borrow_or_share::{Shared, SharesWith, Static};
+
+    struct Text<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Text<T> {
+        fn as_str(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn shares<B: SharesWith<str>>(_: &B) {}
+
+    fn name(text: Text<Static<str>>) -> &'static str {
+        text.as_str()
+    }
+
+    fn share<'a>(text: &Text<Shared<'a, str>>) -> &'a str {
+        text.as_str()
+    }
+
+    assert_eq!(name(Text(Shared("main"))), "main");
+
+    let s = core::str::from_utf8(b"hello").unwrap();
+    let shared = {
+        let text = Text(Shared::from(s));
+        shares(&text.0);
+        share(&text)
+    };
+    assert_eq!(shared, "hello");
+
+    let copy = Shared(s);
+    assert_eq!(copy.len(), 5);
+    assert_eq!(copy.get(), copy.0);
+}
+
+#[test]
+fn chunks_and_windows() {
+    use borrow_or_share::BorrowOrShareExt;
+
+    struct Signal<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, [f32]>> Signal<T> {
+        fn frames(&'i self, size: usize) -> Vec<&'o [f32]> {
+            self.0.borrow_or_share_chunks(size).collect()
+        }
+
+        fn moving_sums(&'i self, size: usize) -> Vec<f32> {
+            self.0
+                .borrow_or_share_windows(size)
+                .map(|w| w.iter().sum())
+                .collect()
+        }
+    }
+
+    fn share<'a>(signal: &Signal<&'a [f32]>) -> Vec<&'a [f32]> {
+        signal.frames(2)
+    }
+
+    let samples = [0.5f32, 1.0, 1.5, 2.0, 2.5];
+    let frames = {
+        let signal = Signal(&samples[..]);
+        assert_eq!(signal.moving_sums(3), [3.0, 4.5, 6.0]);
+        share(&signal)
+    };
+    assert_eq!(frames, [&[0.5, 1.0][..], &[1.5, 2.0], &[2.5]]);
+
+    let owned = Signal(samples);
+    assert_eq!(owned.frames(5), [&samples[..]]);
+    assert!(owned.moving_sums(6).is_empty());
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+struct Pair<B, M>(B, M);
+
+struct Bounded<'a, T: ?Sized>(&'a T);
+
+borrow_or_share::forward_bos!({T} Wrapper<T> => str, via .inner: T);
+borrow_or_share::forward_bos!({B, M} Pair<B, M> => [u8], via .0: B where M: Copy);
+borrow_or_share::forward_bos!({'a, T: ?Sized} Bounded<'a, T> => T, via .0: &'a T);
+
+#[test]
+fn forward_bos_macro() {
+    use borrow_or_share::{BorrowsFrom, Bos, Shares, SharesWith, StableBos};
+
+    fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    fn share<'a>(w: &Wrapper<&'a str>) -> &'a str {
+        as_str(w)
+    }
+
+    fn shares<T: ?Sized, B: SharesWith<T>>(_: &B) {}
+    fn borrows<T: ?Sized, B: BorrowsFrom<T>>(_: &B) {}
+    fn stable<T: ?Sized, B: StableBos<T>>(_: &B) {}
+
+    fn share_via<'a, B: Shares<'a, str>>(b: B) -> &'a str {
+        b.share()
+    }
+
+    let s = core::str::from_utf8(b"hello").unwrap();
+    let shared = {
+        let w = Wrapper { inner: s };
+        shares::<str, _>(&w);
+        stable::<str, _>(&w);
+        share(&w)
+    };
+    assert_eq!(shared, "hello");
+    assert_eq!(share_via(Wrapper { inner: s }), "hello");
+    assert_eq!(Wrapper { inner: "x" }.try_borrow_or_share(), Some("x"));
+
+    let mut buf = *b"abc";
+    let pair = Pair(&mut buf, 'm');
+    borrows::<[u8], _>(&pair);
+    assert_eq!(<Pair<_, _> as Bos<[u8]>>::borrow_or_share(&pair), b"abc");
+    assert_eq!(pair.1, 'm');
+
+    let n = 42;
+    let r: &i32 = {
+        let b = Bounded(&n);
+        b.borrow_or_share()
+    };
+    assert_eq!(*r, 42);
+    assert_eq!(Bounded(&n).share(), &42);
+}
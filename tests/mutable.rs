@@ -0,0 +1,94 @@
+use borrow_or_share::BorrowOrShareMut;
+
+struct BufMut<T>(T);
+
+impl<'i, 'o, T: BorrowOrShareMut<'i, 'o, [u8]>> BufMut<T> {
+    fn as_mut_slice(&'i mut self) -> &'o mut [u8] {
+        self.0.borrow_or_share_mut()
+    }
+}
+
+#[test]
+fn array_and_mut_ref() {
+    let mut buf = BufMut([1u8, 2, 3]);
+    buf.as_mut_slice()[0] = 0;
+    assert_eq!(buf.0, [0, 2, 3]);
+
+    let mut data = [1u8, 2, 3];
+    let mut buf = BufMut(&mut data[..]);
+    buf.as_mut_slice().reverse();
+    assert_eq!(data, [3, 2, 1]);
+}
+
+#[test]
+fn large_array() {
+    let mut buf = BufMut([0u8; 16]);
+    for (i, b) in buf.as_mut_slice().iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    assert_eq!(buf.0[15], 15);
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use super::BufMut;
+    use borrow_or_share::BorrowOrShareMut;
+    use std::borrow::Cow;
+
+    struct TextMut<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShareMut<'i, 'o, str>> TextMut<T> {
+        fn as_mut_str(&'i mut self) -> &'o mut str {
+            self.0.borrow_or_share_mut()
+        }
+    }
+
+    #[test]
+    fn string_box_and_cow() {
+        let mut text = TextMut(String::from("abc"));
+        text.as_mut_str().make_ascii_uppercase();
+        assert_eq!(text.0, "ABC");
+
+        let mut text = TextMut(Box::<str>::from("abc"));
+        text.as_mut_str().make_ascii_uppercase();
+        assert_eq!(&*text.0, "ABC");
+
+        let mut text = TextMut(Cow::Borrowed("abc"));
+        text.as_mut_str().make_ascii_uppercase();
+        assert!(matches!(text.0, Cow::Owned(ref s) if s == "ABC"));
+    }
+
+    #[test]
+    fn string_mut_ref() {
+        fn shout(s: &mut String) {
+            s.push('!');
+            TextMut(s).as_mut_str().make_ascii_uppercase();
+        }
+
+        let mut s = String::from("abc");
+        shout(&mut s);
+        assert_eq!(s, "ABC!");
+
+        let mut text = TextMut(&mut s);
+        text.as_mut_str()[..1].make_ascii_lowercase();
+        assert_eq!(s, "aBC!");
+    }
+
+    #[test]
+    fn vec() {
+        let mut buf = BufMut(vec![1u8, 2, 3]);
+        buf.as_mut_slice().fill(7);
+        assert_eq!(buf.0, [7, 7, 7]);
+    }
+
+    #[test]
+    fn boxed() {
+        let mut buf = BufMut(Box::<[u8]>::from([0u8; 16]));
+        buf.as_mut_slice()[8..].fill(1);
+        assert_eq!(buf.0.iter().map(|&b| b as usize).sum::<usize>(), 8);
+
+        let mut x = Box::new(1u32);
+        *BorrowOrShareMut::<u32>::borrow_or_share_mut(&mut x) += 1;
+        assert_eq!(*x, 2);
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "ndarray")]
+
+use borrow_or_share::{
+    BorrowOrShare, BorrowsFrom, BosToOwned, Shares, SharesWith, TryBorrowOrShare,
+};
+use ndarray::{s, Array1, ArrayView1};
+
+fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [f64]>>(b: &'i B) -> &'o [f64] {
+    b.borrow_or_share()
+}
+
+fn try_slice<'i, 'o, B: TryBorrowOrShare<'i, 'o, [f64]>>(b: &'i B) -> Option<&'o [f64]> {
+    b.try_borrow_or_share()
+}
+
+#[test]
+fn contiguous() {
+    fn borrows<B: BorrowsFrom<[f64]>>(_: &B) {}
+    fn shares<B: SharesWith<[f64]>>(_: &B) {}
+
+    let array = Array1::from(vec![1.0, 2.0, 3.0]);
+    borrows(&array);
+    assert_eq!(slice(&array), [1.0, 2.0, 3.0]);
+    assert_eq!(try_slice(&array), Some(&[1.0, 2.0, 3.0][..]));
+
+    let shared = {
+        let view = array.view();
+        shares(&view);
+        slice(&view)
+    };
+    assert_eq!(shared, [1.0, 2.0, 3.0]);
+    assert_eq!(array.view().share(), [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn strided() {
+    let array = Array1::from(vec![1.0, 2.0, 3.0, 4.0]);
+    let view: ArrayView1<'_, f64> = array.slice(s![..;2]);
+    assert_eq!(view.to_vec(), [1.0, 3.0]);
+    assert_eq!(try_slice(&view), None);
+
+    let mut inverted = array.clone();
+    inverted.invert_axis(ndarray::Axis(0));
+    assert_eq!(try_slice(&inverted), None);
+}
+
+#[test]
+fn strided_to_owned() {
+    let array = Array1::from(vec![1.0, 2.0, 3.0, 4.0]);
+    let view = array.slice(s![..;2]);
+    assert_eq!(BosToOwned::<[f64]>::to_owned_target(&view), [1.0, 3.0]);
+    assert_eq!(BosToOwned::<[f64]>::into_owned_target(view), [1.0, 3.0]);
+
+    let mut inverted = array;
+    inverted.invert_axis(ndarray::Axis(0));
+    assert_eq!(BosToOwned::<[f64]>::to_owned_target(&inverted), [4.0, 3.0, 2.0, 1.0]);
+    assert_eq!(BosToOwned::<[f64]>::into_owned_target(inverted), [4.0, 3.0, 2.0, 1.0]);
+}
+
+#[test]
+#[should_panic = "`ArrayView1` is not contiguous"]
+fn strided_panic() {
+    let array = Array1::from(vec![1.0, 2.0, 3.0, 4.0]);
+    slice(&array.slice(s![..;2]));
+}
+
+#[test]
+#[should_panic = "`ArrayView1` is not contiguous"]
+fn strided_share_panic() {
+    let array = Array1::from(vec![1.0, 2.0, 3.0, 4.0]);
+    let _: &[f64] = array.slice(s![..;2]).share();
+}
@@ -0,0 +1,28 @@
+use borrow_or_share::prelude::*;
+use core::cell::RefCell;
+
+#[test]
+fn methods() {
+    let mut buf = [1u8, 2, 3];
+    assert_eq!(buf.borrow_or_share(), [1, 2, 3]);
+    assert_eq!(buf.borrowed(), [1, 2, 3]);
+    assert!(buf.borrow_or_share_validate(|b: &[u8]| b.len() == 3).is_some());
+    BorrowOrShareMut::<[u8]>::borrow_or_share_mut(&mut buf)[0] = 4;
+    assert_eq!(buf.try_borrow_or_share(), Some(&[4, 2, 3][..]));
+    assert_eq!(DynBos::<[u8]>::dyn_borrow(&buf), [4, 2, 3]);
+
+    let cell = RefCell::new(buf);
+    assert_eq!(GuardedBos::<[u8]>::borrow_guarded(&cell).as_target(), [4, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn alloc_methods() {
+    use std::borrow::Cow;
+
+    let s = String::from("hello");
+    assert!(matches!(s.to_cow(), Cow::Borrowed("hello")));
+    assert_eq!(s.to_owned_target(), "hello");
+    assert_eq!(BosToOwned::<str>::into_owned_target(s.clone()), "hello");
+    assert_eq!(s.borrow_or_share_leak(), "hello");
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "ref-cast", feature = "alloc"))]
+
+use borrow_or_share::{BorrowOrShare, BorrowsFrom, CastTarget, SharesWith};
+use ref_cast::RefCast;
+
+#[derive(Debug, PartialEq, RefCast)]
+#[repr(transparent)]
+struct Ident(str);
+
+impl Ident {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn ident<'i, 'o, B: BorrowOrShare<'i, 'o, Ident>>(b: &'i B) -> &'o Ident {
+    b.borrow_or_share()
+}
+
+#[test]
+fn borrow() {
+    fn borrows<B: BorrowsFrom<Ident>>(_: &B) {}
+
+    let owned = CastTarget::<_, Ident>::new(String::from("main"));
+    borrows(&owned);
+    assert_eq!(ident(&owned).as_str(), "main");
+    assert_eq!(ident(&owned), Ident::ref_cast("main"));
+}
+
+#[test]
+fn share() {
+    fn shares<B: SharesWith<Ident>>(_: &B) {}
+
+    fn share<'a>(b: &CastTarget<&'a str, Ident>) -> &'a Ident {
+        ident(b)
+    }
+
+    let s = String::from("helper");
+    let shared = {
+        let b = CastTarget::new(s.as_str());
+        shares(&b);
+        share(&b)
+    };
+    assert_eq!(shared.as_str(), "helper");
+}
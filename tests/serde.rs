@@ -0,0 +1,57 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use borrow_or_share::{serialize_bos, Bos, SerializeVia};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Node<T: Bos<str>, D: Bos<[u8]>> {
+    #[serde(serialize_with = "serialize_bos::<str, _, _>")]
+    name: T,
+    #[serde(serialize_with = "serialize_bos::<[u8], _, _>")]
+    data: D,
+}
+
+#[test]
+fn serialize_with() {
+    let owned = Node {
+        name: String::from("div"),
+        data: vec![1, 2],
+    };
+    let shared = Node {
+        name: "div",
+        data: &[1u8, 2][..],
+    };
+    let expected = r#"{"name":"div","data":[1,2]}"#;
+    assert_eq!(serde_json::to_string(&owned).unwrap(), expected);
+    assert_eq!(serde_json::to_string(&shared).unwrap(), expected);
+}
+
+#[test]
+fn serialize_via() {
+    let s = String::from("hello");
+    let shared = SerializeVia::<_, str>::new(s.as_str());
+    let owned = SerializeVia::<_, str>::new(s.clone());
+    assert_eq!(serde_json::to_string(&shared).unwrap(), r#""hello""#);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), r#""hello""#);
+
+    let bytes = SerializeVia::<_, [u8]>::new(*b"hi");
+    assert_eq!(serde_json::to_string(&bytes).unwrap(), "[104,105]");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn serialize_path() {
+    use std::path::{Path, PathBuf};
+
+    let path = SerializeVia::<_, Path>::new(PathBuf::from("a/b"));
+    assert_eq!(serde_json::to_string(&path).unwrap(), r#""a/b""#);
+
+    #[cfg(unix)]
+    {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let invalid = SerializeVia::<_, Path>::new(Path::new(OsStr::from_bytes(b"\xff")));
+        let err = serde_json::to_string(&invalid).unwrap_err();
+        assert_eq!(err.to_string(), "path contains invalid UTF-8 characters");
+    }
+}
@@ -0,0 +1,356 @@
+#![cfg(feature = "std")]
+
+use borrow_or_share::{
+    BorrowOrShare, BorrowsFrom, Bos, BosGuard, GuardedBos, Segments, SharesWith, TryBorrowOrShare,
+};
+use std::{
+    cell::{Cell, RefCell},
+    ffi::{OsStr, OsString},
+    io::{Cursor, Read},
+    net::Ipv6Addr,
+    sync::{Mutex, RwLock},
+};
+
+struct Text<T>(T);
+
+impl<'i, 'o, T: BorrowOrShare<'i, 'o, OsStr>> Text<T> {
+    fn as_os_str(&'i self) -> &'o OsStr {
+        self.0.borrow_or_share()
+    }
+}
+
+#[test]
+fn os_string_ref() {
+    fn share<'a>(text: &Text<&'a OsString>) -> &'a OsStr {
+        text.as_os_str()
+    }
+
+    let s = OsString::from("hello");
+    let shared = {
+        let text = Text(&s);
+        share(&text)
+    };
+    assert_eq!(shared, "hello");
+}
+
+fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]>>(b: &'i B) -> &'o [u8] {
+    b.borrow_or_share()
+}
+
+#[test]
+fn cursor_borrow() {
+    let mut cursor = Cursor::new(vec![1, 2, 3]);
+    let mut buf = [0; 2];
+    cursor.read_exact(&mut buf).unwrap();
+
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(bytes(&cursor), [1, 2, 3]);
+}
+
+#[test]
+fn cursor_markers() {
+    fn borrows<B: BorrowsFrom<[u8]>>(_: &B) {}
+    fn shares<B: SharesWith<[u8]>>(_: &B) {}
+
+    borrows(&Cursor::new(vec![0u8]));
+    shares(&Cursor::new(&[0u8][..]));
+}
+
+#[test]
+fn cursor_share() {
+    fn share<'a>(cursor: &Cursor<&'a [u8]>) -> &'a [u8] {
+        bytes(cursor)
+    }
+
+    let data = [4, 5, 6];
+    let shared = {
+        let cursor = Cursor::new(&data[..]);
+        share(&cursor)
+    };
+    assert_eq!(shared, [4, 5, 6]);
+}
+
+#[test]
+#[cfg(bos_once_cell)]
+fn once_lock() {
+    use std::sync::OnceLock;
+
+    static NAME: OnceLock<String> = OnceLock::new();
+
+    fn name() -> Option<&'static str> {
+        NAME.try_borrow_or_share()
+    }
+
+    assert_eq!(name(), None);
+    NAME.set(String::from("hello")).unwrap();
+    assert_eq!(name(), Some("hello"));
+}
+
+#[test]
+fn segments() {
+    fn array<'i, 'o, B: BorrowOrShare<'i, 'o, [u16; 8]>>(b: &'i B) -> &'o [u16; 8] {
+        b.borrow_or_share()
+    }
+
+    fn slice<'i, 'o, B: BorrowOrShare<'i, 'o, [u16]>>(b: &'i B) -> &'o [u16] {
+        b.borrow_or_share()
+    }
+
+    let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    let segs = Segments::from(addr);
+    assert_eq!(*array(&segs), addr.segments());
+    assert_eq!(slice(&segs), addr.segments());
+    assert_eq!(segs.to_addr(), addr);
+}
+
+fn guarded_len<B: GuardedBos<str>>(b: &B) -> usize {
+    B::borrow_guarded(b).as_target().len()
+}
+
+#[test]
+fn guarded() {
+    let cell = RefCell::new(String::from("hello"));
+    assert_eq!(guarded_len(&cell), 5);
+    cell.borrow_mut().push('!');
+    assert_eq!(guarded_len(&cell), 6);
+
+    let mutex = Mutex::new(Box::<str>::from("hello"));
+    assert_eq!(guarded_len(&mutex), 5);
+
+    let lock = RwLock::new(String::from("hello"));
+    assert_eq!(guarded_len(&lock), 5);
+    let guard = RwLock::borrow_guarded(&lock);
+    assert_eq!(guard.as_target(), "hello");
+}
+
+#[test]
+fn guarded_and_bos() {
+    struct Text(String);
+
+    impl Bos<str> for Text {
+        type Ref<'this> = &'this str where Self: 'this;
+
+        fn borrow_or_share(this: &Self) -> Self::Ref<'_> {
+            &this.0
+        }
+    }
+
+    impl GuardedBos<str> for Text {
+        type Guard<'this> = &'this str where Self: 'this;
+
+        fn borrow_guarded(this: &Self) -> Self::Guard<'_> {
+            &this.0
+        }
+    }
+
+    fn as_str<'i, 'o, B: BorrowOrShare<'i, 'o, str>>(b: &'i B) -> &'o str {
+        b.borrow_or_share()
+    }
+
+    let text = Text(String::from("hello"));
+    assert_eq!(as_str(&text), "hello");
+    assert_eq!(guarded_len(&text), 5);
+    assert_eq!(guarded_len(&RefCell::new(text)), 5);
+}
+
+#[test]
+#[cfg(bos_lazy_lock)]
+#[allow(clippy::incompatible_msrv)]
+fn lazy_lock() {
+    use std::sync::LazyLock;
+
+    static NAME: LazyLock<String> = LazyLock::new(|| String::from("hello"));
+    static BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| vec![1, 2, 3]);
+
+    struct Config<T>(T);
+
+    impl<'i, 'o, T: BorrowOrShare<'i, 'o, str>> Config<T> {
+        fn name(&'i self) -> &'o str {
+            self.0.borrow_or_share()
+        }
+    }
+
+    fn share(config: &Config<&'static LazyLock<String>>) -> &'static str {
+        config.name()
+    }
+
+    let name = {
+        let config = Config(&NAME);
+        share(&config)
+    };
+    assert_eq!(name, "hello");
+    assert!(std::ptr::eq(name, NAME.as_str()));
+
+    let bytes: &'static [u8] = {
+        let r = &BYTES;
+        bytes(&r)
+    };
+    assert_eq!(bytes, [1, 2, 3]);
+
+    let lazy = LazyLock::new(|| 42);
+    let n: &i32 = {
+        let r = &lazy;
+        r.borrow_or_share()
+    };
+    assert_eq!(*n, 42);
+}
+
+#[test]
+fn borrow_from_self() {
+    use borrow_or_share::BorrowFromSelf;
+    use std::{
+        borrow::Cow,
+        collections::VecDeque,
+        ffi::{CStr, CString},
+        path::{Path, PathBuf},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    fn check<T: ?Sized, B: BorrowFromSelf<T> + ?Sized>(_: &B) {}
+
+    check::<str, _>(&"str");
+    check::<String, _>(&&mut String::new());
+    check::<[u8], _>(&[0u8; 4]);
+    check::<[Cell<u8>], _>(&Cell::new([0u8; 4]));
+    check::<[u8], _>(&vec![0u8]);
+    check::<[u8], _>(&VecDeque::<u8>::new());
+    check::<str, _>(&String::new());
+    check::<CStr, _>(&CString::default());
+    check::<OsStr, _>(&OsString::new());
+    check::<Path, _>(&PathBuf::new());
+    check::<str, _>(&Box::<str>::from(""));
+    check::<str, _>(&Cow::Borrowed(""));
+    check::<str, _>(&Rc::<str>::from(""));
+    check::<str, _>(&Arc::<str>::from(""));
+    check::<[u8], _>(&Cursor::new(vec![0u8]));
+    check::<[u8], _>(&&vec![0u8]);
+    check::<str, _>(&&String::new());
+    check::<OsStr, _>(&&OsString::new());
+    check::<[u16], _>(&Segments::from(Ipv6Addr::LOCALHOST));
+
+    let text = Text(String::from("hello"));
+    assert_eq!(text.0.borrowed(), "hello");
+}
+
+#[test]
+fn display_via() {
+    use borrow_or_share::DisplayVia;
+    use std::path::{Path, PathBuf};
+
+    let path = DisplayVia::<_, Path>::new(PathBuf::from("dir/file name.txt"));
+    assert_eq!(format!("{path}"), "dir/file name.txt");
+    assert_eq!(format!("{path:?}"), r#""dir/file name.txt""#);
+
+    let os = DisplayVia::<_, OsStr>::new(OsString::from("hello"));
+    assert_eq!(format!("{os}"), "hello");
+    assert_eq!(format!("{os:?}"), r#""hello""#);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let os = DisplayVia::<_, OsStr>::new(OsStr::from_bytes(b"a\xffb"));
+        assert_eq!(format!("{os}"), "a\u{FFFD}b");
+    }
+}
+
+#[test]
+fn boo_path() {
+    use borrow_or_share::Boo;
+    use std::path::{Path, PathBuf};
+
+    fn path<'i, 'o, B: BorrowOrShare<'i, 'o, Path>>(b: &'i B) -> &'o Path {
+        b.borrow_or_share()
+    }
+
+    let borrowed = Boo::from(Path::new("dir/file"));
+    let owned = Boo::<Path>::from(PathBuf::from("dir/file"));
+    assert_eq!(path(&borrowed), path(&owned));
+    assert_eq!(borrowed.shared(), Some(Path::new("dir/file")));
+    assert_eq!(owned.into_owned(), PathBuf::from("dir/file"));
+}
+
+#[test]
+fn os_str_try_str() {
+    fn try_str<'i, 'o, B>(b: &'i B) -> Option<&'o str>
+    where
+        B: TryBorrowOrShare<'i, 'o, str> + ?Sized,
+    {
+        b.try_borrow_or_share()
+    }
+
+    fn share<'a>(os: &&'a OsStr) -> Option<&'a str> {
+        try_str(os)
+    }
+
+    let os = OsString::from("utf-8");
+    assert_eq!(try_str(&os), Some("utf-8"));
+    assert_eq!(try_str(os.as_os_str()), Some("utf-8"));
+    assert_eq!(try_str(&&os), Some("utf-8"));
+
+    let shared = {
+        let r = os.as_os_str();
+        share(&r)
+    };
+    assert_eq!(shared, Some("utf-8"));
+
+    #[cfg(unix)]
+    let invalid = {
+        use std::os::unix::ffi::OsStringExt;
+        OsString::from_vec(vec![b'a', 0xff])
+    };
+    #[cfg(windows)]
+    let invalid = {
+        use std::os::windows::ffi::OsStringExt;
+        OsString::from_wide(&[0x61, 0xd800])
+    };
+    #[cfg(any(unix, windows))]
+    {
+        assert_eq!(try_str(&invalid), None);
+        assert_eq!(try_str(&invalid.as_os_str()), None);
+    }
+}
+
+#[test]
+#[cfg(bos_os_str_bytes)]
+fn borrow_or_share_bytes() {
+    use borrow_or_share::BorrowOrShareExt;
+    use std::path::PathBuf;
+
+    let os = OsString::from("os");
+    assert_eq!(os.borrow_or_share_bytes(), b"os");
+    assert_eq!(PathBuf::from("a/b").borrow_or_share_bytes(), b"a/b");
+}
+
+#[test]
+#[cfg(bos_once_cell)]
+fn sync_lazy_bos() {
+    use borrow_or_share::SyncLazyBos;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    };
+
+    fn len<'i, 'o, B: BorrowOrShare<'i, 'o, String>>(b: &'i B) -> usize {
+        b.borrow_or_share().len()
+    }
+
+    let calls = AtomicUsize::new(0);
+    let lazy = SyncLazyBos::new(|| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        String::from("computed")
+    });
+
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    assert_eq!(len(&lazy), 8);
+                }
+            });
+        }
+    });
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(SyncLazyBos::get(&lazy).map(String::as_str), Some("computed"));
+}
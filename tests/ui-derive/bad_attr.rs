@@ -0,0 +1,7 @@
+use borrow_or_share::Bos;
+
+#[derive(Bos)]
+#[bos(traget = str)]
+struct Name(String);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use borrow_or_share::Bos;
+
+#[derive(Bos)]
+enum Text {
+    Owned(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use borrow_or_share::Bos;
+
+#[derive(Bos)]
+struct Label {
+    #[bos]
+    text: String,
+    #[bos]
+    alt: String,
+}
+
+fn main() {}
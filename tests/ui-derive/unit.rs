@@ -0,0 +1,6 @@
+use borrow_or_share::Bos;
+
+#[derive(Bos)]
+struct Empty;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use borrow_or_share::Bos;
+
+#[derive(Bos)]
+struct Label {
+    text: String,
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// The expected output depends on the set of implementations,
+// so only check it with the default features on a recent stable compiler.
+#[test]
+#[cfg_attr(
+    any(
+        not(feature = "alloc"),
+        feature = "std",
+        feature = "glib",
+        feature = "nightly",
+        not(bos_diagnostic),
+        bos_nightly_rustc,
+    ),
+    ignore = "diagnostics are only checked with the default features"
+)]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use borrow_or_share::BorrowOrShare;
+
+fn as_str<'i, 'o, T: BorrowOrShare<'i, 'o, str>>(t: &'i T) -> &'o str {
+    t.borrow_or_share()
+}
+
+fn main() {
+    as_str(&42u32);
+}
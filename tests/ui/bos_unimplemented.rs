@@ -0,0 +1,7 @@
+use borrow_or_share::Bos;
+
+fn as_str<T: Bos<str>>(_: &T) {}
+
+fn main() {
+    as_str(&42u32);
+}
@@ -0,0 +1,19 @@
+use std::borrow::Borrow;
+
+struct MyVec<T>(Vec<T>);
+
+impl<T> Borrow<[T]> for MyVec<T> {
+    fn borrow(&self) -> &[T] {
+        &self.0
+    }
+}
+
+borrow_or_share::impl_bos_via_borrow! {
+    MyVec<T> => [T];
+}
+
+borrow_or_share::impl_bos_via_borrow! {
+    {T} MyVec<U> => [U];
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+struct MyString(String);
+
+borrow_or_share::impl_bos_via_borrow! {
+    MyString;
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![cfg(feature = "zerovec")]
+
+use borrow_or_share::BorrowOrShare;
+use zerovec::{ZeroSlice, ZeroVec};
+
+fn bytes<'i, 'o, B: BorrowOrShare<'i, 'o, [u8]> + ?Sized>(b: &'i B) -> &'o [u8] {
+    b.borrow_or_share()
+}
+
+#[test]
+fn zero_vec() {
+    let borrowed = ZeroVec::<u8>::new_borrowed(&[4, 5]);
+    assert!(!borrowed.is_owned());
+    assert_eq!(bytes(&borrowed), [4, 5]);
+    assert_eq!(bytes(ZeroSlice::<u8>::new_empty()), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned() {
+    use borrow_or_share::BosToOwned;
+
+    let owned: ZeroVec<'_, u8> = ZeroVec::alloc_from_slice(&[1, 2, 3]);
+    assert!(owned.is_owned());
+    assert_eq!(bytes(&owned), [1, 2, 3]);
+
+    let vec = BosToOwned::<[u8]>::into_owned_target(owned);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn shared_ref() {
+    fn share<'a>(v: &&'a ZeroVec<'_, u8>) -> &'a [u8] {
+        bytes(v)
+    }
+
+    let vec = ZeroVec::<u8>::new_borrowed(&[1, 2, 3]);
+    let shared = {
+        let r = &vec;
+        share(&r)
+    };
+    assert_eq!(shared, [1, 2, 3]);
+}